@@ -59,8 +59,8 @@ def main():
     
     # Auto-fix common issues
     print("\n2. Auto-fixing common issues...")
-    fixed_json, num_fixes = auto_fix_genome(genome_json)
-    print(f"   Applied {num_fixes} automatic fixes")
+    fixed_json, report = auto_fix_genome(genome_json)
+    print(f"   Applied {len(report)} automatic fixes")
     
     # Validate fixed genome
     print("\n3. Re-validating fixed genome...")
@@ -35,7 +35,7 @@ fn generate_motor_registration_functions() -> String {
             &variant.snake_case_name,
             &variant.accepted_wrapped_io_data_type
         ));
-        functions.push_str(&motor_shared_functions(&variant.snake_case_name, &variant.accepted_wrapped_io_data_type));
+        functions.push_str(&motor_shared_functions(&variant.name, &variant.snake_case_name, &variant.accepted_wrapped_io_data_type));
 
         functions.push_str("    //endregion\n\n");
     }
@@ -70,7 +70,7 @@ fn generate_sensor_registration_functions() -> String {
 //region Motor
 
 #[allow(dead_code)]
-fn motor_shared_functions(snake_case_name: &str, accepted_wrapped_io_data_type: &str) -> String {
+fn motor_shared_functions(name: &str, snake_case_name: &str, accepted_wrapped_io_data_type: &str) -> String {
     format!(
         r#"
     pub fn motor_{snake_case_name}_read_preprocessed_cached_value(
@@ -213,29 +213,31 @@ fn motor_shared_functions(snake_case_name: &str, accepted_wrapped_io_data_type:
         Ok(())
     }}
 
-    /*
+    /// Registers a Python callable that is called with the postprocessed value of the
+    /// given channel every time a decode updates it. Returns the signal index of the callback.
+    /// The callback stays registered if the motor cache is rebuilt, as long as its channel still exists.
     pub fn motor_{snake_case_name}_try_register_motor_callback(
         &mut self,
         py: Python<'_>,
-        group: PyObject,
-        channel: PyObject,
-        callback: PyObject,
-    ) -> PyResult<PyFeagiSignalIndex>
+        group: u8,
+        channel_index: u32,
+        callback: Py<PyAny>,
+    ) -> PyResult<u32>
     {{
-        let group: CorticalUnitIndex = PyCorticalUnitIndex::try_get_from_py_object(py, group).map_err(PyFeagiError::from)?;
-        let channel: CorticalChannelIndex = PyCorticalChannelIndex::try_get_from_py_object(py, channel).map_err(PyFeagiError::from)?;
-
-        // Note: Callback handling would need special implementation for Python callbacks
-        // This is a placeholder that would need proper PyO3 callback wrapping
-        let signal_index = self.inner.get_motor_cache().{snake_case_name}_try_register_motor_callback(
-            group,
-            channel,
-            |_| {{ /* Python callback wrapper */ }}
-        ).map_err(PyFeagiError::from)?;
+        let registration = MotorCallbackRegistration {{
+            unit: MotorCorticalUnit::{name},
+            group: group.into(),
+            channel_index: channel_index.into(),
+            callback,
+            register: |motor_cache, group, channel_index, callback| {{
+                motor_cache.{snake_case_name}_try_register_motor_callback(group, channel_index, callback)
+            }},
+        }};
 
-        Ok(signal_index.into())
+        let signal_index = registration.register_to(py, &mut self.get_motor_cache())?;
+        self.motor_callbacks.push(registration);
+        Ok(*signal_index)
     }}
-    */
     "#,
     )
 }
@@ -4,13 +4,26 @@
  * Exposes the Rust AgentClient to Python as PyAgentClient
  */
 
+pub mod py_agent_capabilities;
 pub mod py_agent_client;
+pub mod py_agent_connection;
+pub mod py_agent_control;
 pub mod py_agent_config;
 pub mod py_agent_type;
+pub mod py_agent_runtime;
+pub mod py_async_agent_client;
+pub mod py_mock_feagi_server;
+pub mod py_visualization_subscriber;
 
 pub use py_agent_client::PyAgentClient;
+pub use py_agent_connection::{PyConnectionEvent, PyConnectionState};
+pub use py_agent_control::PyGenomeMetadata;
 pub use py_agent_config::PyAgentConfig;
 pub use py_agent_type::PyAgentType as AgentType;
+pub use py_agent_runtime::PyAgentRuntime;
+pub use py_async_agent_client::PyAsyncAgentClient;
+pub use py_mock_feagi_server::PyMockFeagiServer;
+pub use py_visualization_subscriber::PyVisualizationSubscriber;
 
 use pyo3::prelude::*;
 use std::sync::Once;
@@ -46,8 +59,15 @@ pub fn register_module(py: Python, parent_module: &Bound<'_, PyModule>) -> PyRes
     
     // Register types
     submodule.add_class::<PyAgentClient>()?;
+    submodule.add_class::<PyConnectionState>()?;
+    submodule.add_class::<PyConnectionEvent>()?;
+    submodule.add_class::<PyGenomeMetadata>()?;
     submodule.add_class::<PyAgentConfig>()?;
     submodule.add_class::<AgentType>()?;
+    submodule.add_class::<PyAgentRuntime>()?;
+    submodule.add_class::<PyAsyncAgentClient>()?;
+    submodule.add_class::<PyMockFeagiServer>()?;
+    submodule.add_class::<PyVisualizationSubscriber>()?;
     
     // Register functions
     submodule.add_function(wrap_pyfunction!(init_rust_logging, &submodule)?)?;
@@ -0,0 +1,154 @@
+/*
+ * Agent capabilities derived from the device registrations of a ConnectorAgent
+ */
+
+use pyo3::prelude::*;
+use heck::ToSnakeCase;
+use serde_json::Value;
+use feagi_agent::core::{AgentCapabilities, MotorCapability, VisionCapability};
+use feagi_data_structures::FeagiDataError;
+use feagi_data_structures::genomic::SensoryCorticalUnit;
+use feagi_io::{MotorUnit, MotorUnitSpec, SensoryUnit};
+use feagi_sensorimotor::data_types::descriptors::ImageFrameProperties;
+use super::py_agent_config::PyAgentConfig;
+use crate::feagi_connector_core::PyConnectorAgent;
+use crate::py_error::PyFeagiError;
+
+/// Custom capability under which FEAGI expects the exported device registrations
+const DEVICE_REGISTRATIONS_CAPABILITY: &str = "device_registrations";
+/// Modalities reported for the derived vision and motor capabilities
+const CONNECTOR_VISION_MODALITY: &str = "camera";
+const CONNECTOR_MOTOR_MODALITY: &str = "connector_agent";
+
+/// Group and channel count of each registered group of a unit, as exported by a ConnectorAgent.
+/// Each entry of a unit is a [unit definition, coder properties] pair
+fn registered_groups(unit_definitions: &Value) -> Result<Vec<(u8, usize, &Value)>, FeagiDataError> {
+    let unit_definitions = unit_definitions.as_array()
+        .ok_or_else(|| FeagiDataError::DeserializationError("Unit registrations must be an array!".into()))?;
+    unit_definitions.iter()
+        .map(|unit_and_coder| {
+            let unit_definition = unit_and_coder.get(0);
+            let group = unit_definition
+                .and_then(|unit_definition| unit_definition.get("cortical_unit_index"))
+                .and_then(Value::as_u64)
+                .and_then(|group| u8::try_from(group).ok())
+                .ok_or_else(|| FeagiDataError::DeserializationError("Unit definition is missing a valid cortical_unit_index!".into()))?;
+            let channel_count = unit_definition
+                .and_then(|unit_definition| unit_definition.get("device_grouping"))
+                .and_then(Value::as_array)
+                .map_or(0, Vec::len);
+            Ok((group, channel_count, unit_and_coder.get(1).unwrap_or(&Value::Null)))
+        })
+        .collect()
+}
+
+/// Maps a unit name of the device registrations (such as "AnalogGPIO") to the registration contract
+/// identifier of FEAGI (such as "analog_gpio"). None for units FEAGI has no identifier for
+fn contract_unit<T: serde::de::DeserializeOwned>(unit_key: &str) -> Option<T> {
+    serde_json::from_value(Value::String(unit_key.to_snake_case())).ok()
+}
+
+/// The vision capability of the registered vision group. FEAGI only describes a single vision input this way,
+/// so registering more than one vision group is an error. Other sensors are only described by the device registrations
+fn derive_vision_capability(registrations: &Value) -> Result<Option<VisionCapability>, FeagiDataError> {
+    let Some(vision_registrations) = registrations.get("input_units_and_encoder_properties")
+        .and_then(|units| units.get("Vision")) else {
+        return Ok(None);
+    };
+    let groups = registered_groups(vision_registrations)?;
+    let (group, _channel_count, encoder_properties) = match groups.as_slice() {
+        [] => return Ok(None),
+        [vision_group] => *vision_group,
+        _ => return Err(FeagiDataError::BadParameters(format!(
+            "FEAGI only accepts a single vision capability, but {} vision groups are registered!", groups.len()))),
+    };
+
+    let image_properties: ImageFrameProperties = encoder_properties.get("CartesianPlane")
+        .map(|image_properties| serde_json::from_value(image_properties.clone()))
+        .transpose()
+        .map_err(|e| FeagiDataError::DeserializationError(format!("Invalid vision encoder properties: {}", e)))?
+        .ok_or_else(|| FeagiDataError::DeserializationError("Vision registration is missing its image properties!".into()))?;
+    let resolution = image_properties.get_image_resolution();
+
+    let io_configuration_flags = vision_registrations.get(0)
+        .and_then(|unit_and_coder| unit_and_coder.get(0))
+        .and_then(|unit_definition| unit_definition.get("io_configuration_flags"))
+        .and_then(Value::as_object)
+        .cloned()
+        .unwrap_or_default();
+    let target_cortical_area = SensoryCorticalUnit::Vision
+        .get_cortical_id_vector_from_index_and_serde_io_configuration_flags(group.into(), io_configuration_flags)?
+        .first()
+        .ok_or_else(|| FeagiDataError::InternalError("Vision unit has no cortical area!".into()))?
+        .as_base_64();
+
+    Ok(Some(VisionCapability {
+        modality: CONNECTOR_VISION_MODALITY.to_string(),
+        dimensions: (resolution.width as usize, resolution.height as usize),
+        channels: usize::from(image_properties.get_color_channel_layout()),
+        target_cortical_area,
+        unit: Some(SensoryUnit::Vision),
+        group: Some(group),
+    }))
+}
+
+/// The motor capability listing every registered motor group FEAGI has an identifier for
+fn derive_motor_capability(registrations: &Value) -> Result<Option<MotorCapability>, FeagiDataError> {
+    let Some(units) = registrations.get("output_units_and_decoder_properties").and_then(Value::as_object) else {
+        return Ok(None);
+    };
+
+    let mut source_units = Vec::new();
+    let mut output_count = 0;
+    for (unit_key, unit_definitions) in units {
+        let Some(unit) = contract_unit::<MotorUnit>(unit_key) else {
+            tracing::debug!("Motor unit {} has no registration contract identifier, only listed in device registrations", unit_key);
+            continue;
+        };
+        let mut groups = registered_groups(unit_definitions)?;
+        groups.sort_by_key(|(group, _, _)| *group);
+        for (group, channel_count, _decoder_properties) in groups {
+            source_units.push(MotorUnitSpec { unit, group });
+            output_count += channel_count;
+        }
+    }
+    if source_units.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(MotorCapability {
+        modality: CONNECTOR_MOTOR_MODALITY.to_string(),
+        output_count,
+        source_cortical_areas: Vec::new(),
+        unit: None,
+        group: None,
+        source_units: Some(source_units),
+    }))
+}
+
+/// Replaces the device related capabilities (device registrations, vision and motor) with those derived from
+/// the registrations exported by a ConnectorAgent. Other capabilities are kept
+fn apply_device_registrations(capabilities: &mut AgentCapabilities, registrations: Value) -> Result<(), FeagiDataError> {
+    capabilities.vision = derive_vision_capability(&registrations)?;
+    capabilities.motor = derive_motor_capability(&registrations)?;
+    capabilities.custom.insert(DEVICE_REGISTRATIONS_CAPABILITY.to_string(), registrations);
+    Ok(())
+}
+
+#[pymethods]
+impl PyAgentConfig {
+    /// Set the device related capabilities from the sensors and motors registered on a ConnectorAgent,
+    /// replacing any set with with_vision_capability / with_motor_capability:
+    /// * `device_registrations` - All registrations, as exported by export_capabilities_json
+    /// * `vision` - The registered vision group, if any. Registering more than one is an error
+    /// * `motor` - Every registered motor group, if any
+    ///
+    /// The registrations are copied, so call this again after registering more devices
+    fn with_connector_capabilities(&mut self, connector: PyRef<'_, PyConnectorAgent>) -> PyResult<()> {
+        let registrations = connector.inner.export_device_registrations_as_config_json()
+            .map_err(PyFeagiError::from)?;
+        apply_device_registrations(&mut self.inner_mut().capabilities, registrations)
+            .map_err(PyFeagiError::from)?;
+        Ok(())
+    }
+}
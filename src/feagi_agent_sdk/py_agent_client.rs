@@ -3,36 +3,75 @@
  */
 
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyBytes, PyList};
+use pyo3::types::{PyAny, PyBytes, PyDict, PyList};
+use feagi_data_structures::neuron_voxels::xyzp::CorticalMappedXYZPNeuronVoxels;
 use super::py_agent_config::PyAgentConfig;
+use super::py_agent_connection::{ConnectionMonitor, ConnectionSupervisor, PyConnectionState};
+use super::py_agent_control::ControlChannel;
+use super::py_visualization_subscriber::PyVisualizationSubscriber;
+use crate::feagi_data_structures::genomic::cortical_area::PyCorticalID;
+use crate::feagi_data_structures::neurons_voxels::xyzp::{PyCorticalMappedXYZPNeuronVoxels, PyNeuronVoxelXYZPArrays};
+use crate::py_error::{PyAgentSdkError, PyFeagiError};
 use std::sync::{Arc, Mutex};
 
 #[pyclass(name = "PyAgentClient")]
 pub struct PyAgentClient {
     inner: Arc<Mutex<feagi_agent::AgentClient>>,
+    config: feagi_agent::AgentConfig,
+    visualization_endpoint: String,
+    control_channel: ControlChannel,
+    connection_monitor: Arc<ConnectionMonitor>,
+    supervisor: Option<ConnectionSupervisor>,
 }
 
 #[pymethods]
 impl PyAgentClient {
     #[new]
     fn new(config: &PyAgentConfig) -> PyResult<Self> {
-        let client = feagi_agent::AgentClient::new(config.inner().clone())
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+        let client = PyAgentClient::new_unconnected_client(config.inner())?;
         
         Ok(PyAgentClient {
             inner: Arc::new(Mutex::new(client)),
+            config: config.inner().clone(),
+            visualization_endpoint: config.inner().visualization_endpoint.clone(),
+            control_channel: ControlChannel::new(config.inner()),
+            connection_monitor: Arc::new(ConnectionMonitor::new()),
+            supervisor: None,
         })
     }
     
-    /// Connect and register with FEAGI
-    fn connect(&mut self) -> PyResult<()> {
-        let mut client = self.inner.lock()
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
-                format!("Lock poisoned: {}", e)
-            ))?;
-        
-        client.connect()
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+    /// Connect and register with FEAGI, then keep the registration alive with heartbeats
+    /// (re-registering after FEAGI restarts) until disconnected
+    pub(crate) fn connect(&mut self, py: Python<'_>) -> PyResult<()> {
+        if self.connection_monitor.state() != PyConnectionState::Disconnected {
+            return Err(PyAgentSdkError::from(feagi_agent::SdkError::AlreadyConnected).into());
+        }
+        self.connection_monitor.transition(PyConnectionState::Connecting, None);
+
+        let inner = &self.inner;
+        let connected = py.detach(|| {
+            let mut client = inner.lock()
+                .map_err(|e| feagi_agent::SdkError::ThreadError(format!("Lock poisoned: {}", e)))?;
+            client.connect()
+        });
+        if let Err(e) = connected {
+            self.connection_monitor.transition(PyConnectionState::Disconnected, Some(e.to_string()));
+            return Err(PyAgentSdkError::from(e).into());
+        }
+        self.connection_monitor.transition(PyConnectionState::Registered, None);
+
+        if self.config.heartbeat_interval > 0.0 {
+            match ConnectionSupervisor::start(&self.config, Arc::clone(&self.connection_monitor)) {
+                Ok(supervisor) => self.supervisor = Some(supervisor),
+                Err(e) => {
+                    // Without heartbeats FEAGI would drop the registration anyway, so give it up now.
+                    // The supervisor error is the one worth reporting, even if deregistering fails too
+                    let _ = self.deregister(py, e.to_string());
+                    return Err(PyAgentSdkError::from(e).into());
+                }
+            }
+        }
+        Ok(())
     }
     
     /// Send sensory data as list of (neuron_id, potential) tuples
@@ -59,7 +98,7 @@ impl PyAgentClient {
         
         // Send data (ZMQ sockets are not Sync, so we can't release GIL)
         client.send_sensory_data(pairs)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+            .map_err(|e| PyAgentSdkError::from(e).into())
     }
 
     /// Send pre-serialized sensory bytes to FEAGI (FeagiByteContainer bytes).
@@ -75,7 +114,7 @@ impl PyAgentClient {
 
         client
             .send_sensory_bytes(bytes)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+            .map_err(|e| PyAgentSdkError::from(e).into())
     }
     
     /// Receive motor data (non-blocking, returns None if no data)
@@ -88,33 +127,54 @@ impl PyAgentClient {
         
         // Receive data (ZMQ sockets are not Sync, so we can't release GIL)
         match client.receive_motor_data() {
-            Ok(Some(data)) => {
-                // Return standard XYZP SoA format: {cortical_id: {x: [...], y: [...], z: [...], p: [...]}}
-                use serde_json::json;
-                
-                let mut result = serde_json::Map::new();
-                
-                for (cortical_id, neuron_voxels) in data.mappings.iter() {
-                    let (x_vec, y_vec, z_vec, p_vec) = neuron_voxels.borrow_xyzp_vectors();
-                    
-                    let mut area_data = serde_json::Map::new();
-                    area_data.insert("x".to_string(), json!(x_vec));
-                    area_data.insert("y".to_string(), json!(y_vec));
-                    area_data.insert("z".to_string(), json!(z_vec));
-                    area_data.insert("p".to_string(), json!(p_vec));
-                    
-                    // Use cortical ID as key (e.g., "omot\x04\x00\x00\x00")
-                    let cortical_id_str = String::from_utf8_lossy(cortical_id.as_bytes()).to_string();
-                    result.insert(cortical_id_str, serde_json::Value::Object(area_data));
-                }
-                
-                // Return standard XYZP SoA JSON
-                let response = serde_json::Value::Object(result);
-                Ok(Some(response.to_string()))
-            },
+            Ok(Some(data)) => Ok(Some(motor_data_to_json(&data))),
             Ok(None) => Ok(None),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string())),
+            Err(e) => Err(PyAgentSdkError::from(e).into()),
+        }
+    }
+
+    /// Receive motor data (non-blocking, returns None if no data)
+    /// Returns motor data as a CorticalMappedXYZPNeuronVoxels
+    fn receive_motor_voxels(&self, py: Python) -> PyResult<Option<Py<PyCorticalMappedXYZPNeuronVoxels>>> {
+        self.receive_motor_data_typed()?
+            .map(|data| PyCorticalMappedXYZPNeuronVoxels::python_etc_child_constructor(py, data))
+            .transpose()
+    }
+
+    /// Receive motor data (non-blocking, returns None if no data)
+    /// Returns motor data as FeagiByteContainer bytes, which can be loaded into a FeagiByteContainer
+    fn receive_motor_bytes<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyBytes>>> {
+        let Some(data) = self.receive_motor_data_typed()? else {
+            return Ok(None);
+        };
+
+        // The client only hands out the decoded structure, so it is serialized back into a container
+        let mut byte_container = feagi_serialization::FeagiByteContainer::new_empty();
+        byte_container.overwrite_byte_data_with_single_struct_data(&data, 0)
+            .map_err(PyFeagiError::from)?;
+        Ok(Some(PyBytes::new(py, byte_container.get_byte_ref())))
+    }
+
+    /// Receive motor data (non-blocking, returns None if no data)
+    /// Returns motor data as a dict of CorticalID to a tuple of numpy arrays (x, y, z, p)
+    fn receive_motor_arrays<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyDict>>> {
+        let Some(data) = self.receive_motor_data_typed()? else {
+            return Ok(None);
+        };
+
+        let result = PyDict::new(py);
+        for (cortical_id, neuron_voxels) in data.mappings.into_iter() {
+            let arrays = PyNeuronVoxelXYZPArrays { inner: neuron_voxels }.copy_as_tuple_of_numpy_arrays(py)?;
+            result.set_item(PyCorticalID::new_from_rust(cortical_id), arrays)?;
         }
+        Ok(Some(result))
+    }
+    
+    /// Subscribe to the visualization stream of the configured FEAGI, optionally only keeping the given
+    /// cortical areas. Works for any agent type, registered or not
+    #[pyo3(signature = (cortical_ids = None))]
+    fn subscribe_visualization(&self, cortical_ids: Option<Vec<PyCorticalID>>) -> PyResult<PyVisualizationSubscriber> {
+        PyVisualizationSubscriber::connect(&self.visualization_endpoint, cortical_ids)
     }
     
     /// Check if agent is registered
@@ -126,16 +186,99 @@ impl PyAgentClient {
         Ok(client.is_registered())
     }
     
-    /// Note: Disconnect happens automatically on drop, no explicit disconnect needed
-    /// This method is kept for compatibility but is a no-op
-    fn disconnect(&mut self) -> PyResult<()> {
-        // Agent automatically deregisters on drop
-        Ok(())
+    /// Stop heartbeats and deregister from FEAGI, waiting for its reply (up to the connection timeout).
+    /// Does nothing if not connected. The client can connect again afterwards
+    pub(crate) fn disconnect(&mut self, py: Python<'_>) -> PyResult<()> {
+        if let Some(mut supervisor) = self.supervisor.take() {
+            py.detach(|| supervisor.stop());
+        }
+        if self.connection_monitor.state() == PyConnectionState::Disconnected {
+            return Ok(());
+        }
+        self.deregister(py, "Disconnected".to_string())
     }
     
     fn __repr__(&self) -> String {
-        let registered = self.is_registered().unwrap_or(false);
-        format!("PyAgentClient(registered={})", registered)
+        format!("PyAgentClient(agent_id={}, state={:?})", self.config.agent_id, self.connection_monitor.state())
     }
 }
 
+impl PyAgentClient {
+    /// Creates the underlying client. Its own heartbeat is disabled, as the connection supervisor sends them
+    fn new_unconnected_client(config: &feagi_agent::AgentConfig) -> PyResult<feagi_agent::AgentClient> {
+        let client_config = config.clone().with_heartbeat_interval(0.0);
+        feagi_agent::AgentClient::new(client_config)
+            .map_err(|e| PyAgentSdkError::from(e).into())
+    }
+
+    /// Deregisters from FEAGI and moves to Disconnected with the given reason, even if deregistering fails
+    fn deregister(&mut self, py: Python<'_>, reason: String) -> PyResult<()> {
+        // The underlying client deregisters when dropped, so it is swapped for a fresh one
+        let swapped = PyAgentClient::new_unconnected_client(&self.config).and_then(|fresh_client| {
+            let mut client = self.inner.lock()
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
+                    format!("Lock poisoned: {}", e)
+                ))?;
+            Ok(std::mem::replace(&mut *client, fresh_client))
+        });
+        let result = swapped.map(|connected_client| py.detach(|| drop(connected_client)));
+
+        self.connection_monitor.transition(PyConnectionState::Disconnected, Some(reason));
+        result
+    }
+
+    /// Receives motor data as decoded by the client (non-blocking, returns None if no data)
+    fn receive_motor_data_typed(&self) -> PyResult<Option<CorticalMappedXYZPNeuronVoxels>> {
+        let client = self.inner.lock()
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
+                format!("Lock poisoned: {}", e)
+            ))?;
+
+        client.receive_motor_data()
+            .map_err(|e| PyAgentSdkError::from(e).into())
+    }
+
+    /// Shared handle to the underlying client, for Rust side users such as the agent runtime
+    pub(crate) fn shared_inner(&self) -> Arc<Mutex<feagi_agent::AgentClient>> {
+        self.inner.clone()
+    }
+
+    /// Control channel to FEAGI, used by the control operations
+    pub(crate) fn control_channel(&self) -> &ControlChannel {
+        &self.control_channel
+    }
+
+    /// Connection state and lifecycle events, shared with the connection supervisor
+    pub(crate) fn connection_monitor(&self) -> &ConnectionMonitor {
+        &self.connection_monitor
+    }
+
+    /// Whether the client subscribes to motor data from FEAGI
+    pub(crate) fn receives_motor_data(&self) -> bool {
+        matches!(self.config.agent_type, feagi_agent::AgentType::Motor | feagi_agent::AgentType::Both)
+    }
+}
+
+/// Converts received motor data to the standard XYZP SoA JSON format:
+/// {cortical_id: {x: [...], y: [...], z: [...], p: [...]}}
+pub(crate) fn motor_data_to_json(data: &CorticalMappedXYZPNeuronVoxels) -> String {
+    use serde_json::json;
+
+    let mut result = serde_json::Map::new();
+
+    for (cortical_id, neuron_voxels) in data.mappings.iter() {
+        let (x_vec, y_vec, z_vec, p_vec) = neuron_voxels.borrow_xyzp_vectors();
+
+        let mut area_data = serde_json::Map::new();
+        area_data.insert("x".to_string(), json!(x_vec));
+        area_data.insert("y".to_string(), json!(y_vec));
+        area_data.insert("z".to_string(), json!(z_vec));
+        area_data.insert("p".to_string(), json!(p_vec));
+
+        // Use cortical ID as key (e.g., "omot\x04\x00\x00\x00")
+        let cortical_id_str = String::from_utf8_lossy(cortical_id.as_bytes()).to_string();
+        result.insert(cortical_id_str, serde_json::Value::Object(area_data));
+    }
+
+    serde_json::Value::Object(result).to_string()
+}
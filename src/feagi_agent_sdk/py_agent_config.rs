@@ -3,7 +3,132 @@
  */
 
 use pyo3::prelude::*;
+use feagi_agent::core::AgentCapabilities;
+use serde::{Deserialize, Serialize};
 use super::py_agent_type::PyAgentType;
+use crate::py_error::PyAgentSdkError;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Serialized form of PyAgentConfig, as read by from_json / from_toml and written by to_json / to_toml.
+/// Only agent_id and agent_type are required, missing fields keep their defaults
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AgentConfigDocument {
+    agent_id: String,
+    agent_type: String,
+    registration_endpoint: Option<String>,
+    sensory_endpoint: Option<String>,
+    motor_endpoint: Option<String>,
+    visualization_endpoint: Option<String>,
+    control_endpoint: Option<String>,
+    heartbeat_interval: Option<f64>,
+    connection_timeout_ms: Option<u64>,
+    registration_retries: Option<u32>,
+    retry_backoff_ms: Option<u64>,
+    sensory_send_hwm: Option<i32>,
+    sensory_linger_ms: Option<i32>,
+    sensory_immediate: Option<bool>,
+    capabilities: Option<AgentCapabilities>,
+}
+
+impl AgentConfigDocument {
+    fn from_config(config: &feagi_agent::AgentConfig) -> Self {
+        AgentConfigDocument {
+            agent_id: config.agent_id.clone(),
+            agent_type: config.agent_type.to_string(),
+            registration_endpoint: Some(config.registration_endpoint.clone()),
+            sensory_endpoint: Some(config.sensory_endpoint.clone()),
+            motor_endpoint: Some(config.motor_endpoint.clone()),
+            visualization_endpoint: Some(config.visualization_endpoint.clone()),
+            control_endpoint: Some(config.control_endpoint.clone()),
+            heartbeat_interval: Some(config.heartbeat_interval),
+            connection_timeout_ms: Some(config.connection_timeout_ms),
+            registration_retries: Some(config.registration_retries),
+            retry_backoff_ms: Some(config.retry_backoff_ms),
+            sensory_send_hwm: Some(config.sensory_send_hwm),
+            sensory_linger_ms: Some(config.sensory_linger_ms),
+            sensory_immediate: Some(config.sensory_immediate),
+            capabilities: Some(config.capabilities.clone()),
+        }
+    }
+
+    fn into_config(self) -> PyResult<feagi_agent::AgentConfig> {
+        let agent_type = feagi_agent::AgentType::from_str(&self.agent_type).map_err(invalid_config)?;
+        let mut config = feagi_agent::AgentConfig::new(self.agent_id, agent_type);
+        config.registration_endpoint = self.registration_endpoint.unwrap_or(config.registration_endpoint);
+        config.sensory_endpoint = self.sensory_endpoint.unwrap_or(config.sensory_endpoint);
+        config.motor_endpoint = self.motor_endpoint.unwrap_or(config.motor_endpoint);
+        config.visualization_endpoint = self.visualization_endpoint.unwrap_or(config.visualization_endpoint);
+        config.control_endpoint = self.control_endpoint.unwrap_or(config.control_endpoint);
+        config.heartbeat_interval = self.heartbeat_interval.unwrap_or(config.heartbeat_interval);
+        config.connection_timeout_ms = self.connection_timeout_ms.unwrap_or(config.connection_timeout_ms);
+        config.registration_retries = self.registration_retries.unwrap_or(config.registration_retries);
+        config.retry_backoff_ms = self.retry_backoff_ms.unwrap_or(config.retry_backoff_ms);
+        config.sensory_send_hwm = self.sensory_send_hwm.unwrap_or(config.sensory_send_hwm);
+        config.sensory_linger_ms = self.sensory_linger_ms.unwrap_or(config.sensory_linger_ms);
+        config.sensory_immediate = self.sensory_immediate.unwrap_or(config.sensory_immediate);
+        config.capabilities = self.capabilities.unwrap_or(config.capabilities);
+        Ok(config)
+    }
+
+    /// Writes the document as TOML. Unset capabilities and capability fields are left out, as TOML has no null
+    fn to_toml(&self) -> PyResult<String> {
+        let document = serde_json::to_value(self)
+            .map_err(|e| PyAgentSdkError::from(feagi_agent::SdkError::from(e)))?;
+        toml::to_string(&without_nulls(document))
+            .map_err(|e| invalid_config(format!("Unable to write configuration as TOML: {}", e)))
+    }
+}
+
+fn invalid_config(message: String) -> PyErr {
+    PyAgentSdkError::from(feagi_agent::SdkError::InvalidConfig(message)).into()
+}
+
+/// Reads an environment variable, None if it is not set
+fn env_string(name: &str) -> PyResult<Option<String>> {
+    match std::env::var(name) {
+        Ok(value) => Ok(Some(value.trim().to_string())),
+        Err(std::env::VarError::NotPresent) => Ok(None),
+        Err(std::env::VarError::NotUnicode(_)) => Err(invalid_config(format!("{} is not valid unicode", name))),
+    }
+}
+
+fn env_value<T: FromStr>(name: &str) -> PyResult<Option<T>> where T::Err: std::fmt::Display {
+    env_string(name)?
+        .map(|value| value.parse::<T>().map_err(|e| invalid_config(format!("Invalid value '{}' for {}: {}", value, name, e))))
+        .transpose()
+}
+
+fn env_bool(name: &str) -> PyResult<Option<bool>> {
+    env_string(name)?
+        .map(|value| match value.to_lowercase().as_str() {
+            "1" | "true" | "yes" | "on" => Ok(true),
+            "0" | "false" | "no" | "off" => Ok(false),
+            _ => Err(invalid_config(format!("Invalid value '{}' for {}: expected true or false", value, name))),
+        })
+        .transpose()
+}
+
+/// Removes null members from objects, as TOML has no null. They are read back as missing optional fields
+fn without_nulls(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(object) => object.into_iter()
+            .filter(|(_, member)| !member.is_null())
+            .map(|(key, member)| (key, without_nulls(member)))
+            .collect(),
+        serde_json::Value::Array(array) => array.into_iter().map(without_nulls).collect(),
+        value => value,
+    }
+}
+
+/// Replaces the host and / or port of a tcp://host:port endpoint
+fn rewrite_endpoint(endpoint: &str, host: Option<&str>, port: Option<u16>) -> Option<String> {
+    let address = endpoint.strip_prefix("tcp://").and_then(|address| address.rsplit_once(':'));
+    let host = host.or(address.map(|(host, _)| host))?;
+    let port = port.map(|port| port.to_string()).or(address.map(|(_, port)| port.to_string()))?;
+    Some(format!("tcp://{}:{}", host, port))
+}
 
 #[pyclass(name = "PyAgentConfig")]
 #[derive(Clone)]
@@ -52,6 +177,18 @@ impl PyAgentConfig {
         Ok(())
     }
     
+    /// Set visualization stream endpoint
+    fn with_visualization_endpoint(&mut self, endpoint: String) -> PyResult<()> {
+        self.inner = self.inner.clone().with_visualization_endpoint(endpoint);
+        Ok(())
+    }
+    
+    /// Set control (REST API over ZMQ) endpoint
+    fn with_control_endpoint(&mut self, endpoint: String) -> PyResult<()> {
+        self.inner = self.inner.clone().with_control_endpoint(endpoint);
+        Ok(())
+    }
+    
     /// Set heartbeat interval in seconds (0 to disable)
     fn with_heartbeat_interval(&mut self, interval: f64) -> PyResult<()> {
         self.inner = self.inner.clone().with_heartbeat_interval(interval);
@@ -70,6 +207,12 @@ impl PyAgentConfig {
         Ok(())
     }
     
+    /// Set retry backoff base in milliseconds
+    fn with_retry_backoff_ms(&mut self, backoff: u64) -> PyResult<()> {
+        self.inner.retry_backoff_ms = backoff;
+        Ok(())
+    }
+    
     /// Set sensory socket configuration (high water mark, linger, immediate)
     fn with_sensory_socket_config(&mut self, hwm: i32, linger_ms: i32, immediate: bool) -> PyResult<()> {
         self.inner = self.inner.clone().with_sensory_socket_config(hwm, linger_ms, immediate);
@@ -123,6 +266,222 @@ impl PyAgentConfig {
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
     }
     
+    /// Load a configuration from JSON, in the format written by to_json
+    #[staticmethod]
+    fn from_json(json: &str) -> PyResult<Self> {
+        let document: AgentConfigDocument = serde_json::from_str(json)
+            .map_err(|e| invalid_config(format!("Invalid JSON agent configuration: {}", e)))?;
+        Ok(PyAgentConfig { inner: document.into_config()? })
+    }
+    
+    /// Load a configuration from TOML, in the format written by to_toml
+    #[staticmethod]
+    fn from_toml(toml: &str) -> PyResult<Self> {
+        let document: AgentConfigDocument = toml::from_str(toml)
+            .map_err(|e| invalid_config(format!("Invalid TOML agent configuration: {}", e)))?;
+        Ok(PyAgentConfig { inner: document.into_config()? })
+    }
+    
+    /// Load a configuration from a .json or .toml file
+    #[staticmethod]
+    fn from_file(path: PathBuf) -> PyResult<Self> {
+        let extension = path.extension().and_then(|extension| extension.to_str()).map(str::to_lowercase);
+        let from_str: fn(&str) -> PyResult<Self> = match extension.as_deref() {
+            Some("json") => PyAgentConfig::from_json,
+            Some("toml") => PyAgentConfig::from_toml,
+            _ => return Err(invalid_config(format!("Unknown configuration file type: {} (expected .json or .toml)", path.display()))),
+        };
+        let contents = std::fs::read_to_string(&path)
+            .map_err(|e| std::io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+        from_str(&contents)
+    }
+    
+    /// Override the configuration from environment variables, returning the names of those that were applied.
+    /// With the default prefix, these are:
+    /// * `FEAGI_AGENT_ID`, `FEAGI_AGENT_TYPE`
+    /// * `FEAGI_HOST` - Replaces the host of all configured endpoints
+    /// * `FEAGI_{REGISTRATION,SENSORY,MOTOR,VISUALIZATION,CONTROL}_PORT` - Replaces the port of an endpoint
+    /// * `FEAGI_{REGISTRATION,SENSORY,MOTOR,VISUALIZATION,CONTROL}_ENDPOINT` - Replaces an endpoint entirely
+    /// * `FEAGI_HEARTBEAT_INTERVAL`, `FEAGI_CONNECTION_TIMEOUT_MS`, `FEAGI_REGISTRATION_RETRIES`, `FEAGI_RETRY_BACKOFF_MS`
+    /// * `FEAGI_SENSORY_SEND_HWM`, `FEAGI_SENSORY_LINGER_MS`, `FEAGI_SENSORY_IMMEDIATE`
+    #[pyo3(signature = (prefix = "FEAGI_"))]
+    fn apply_env_overrides(&mut self, prefix: &str) -> PyResult<Vec<String>> {
+        let mut config = self.inner.clone();
+        let mut applied = Vec::new();
+        let mut take = |name: &str| {
+            applied.push(format!("{}{}", prefix, name));
+        };
+        
+        if let Some(agent_id) = env_string(&format!("{}AGENT_ID", prefix))? {
+            config.agent_id = agent_id;
+            take("AGENT_ID");
+        }
+        if let Some(agent_type) = env_value::<feagi_agent::AgentType>(&format!("{}AGENT_TYPE", prefix))? {
+            config.agent_type = agent_type;
+            take("AGENT_TYPE");
+        }
+        
+        let host = env_string(&format!("{}HOST", prefix))?;
+        if host.is_some() {
+            take("HOST");
+        }
+        let endpoints = [
+            ("REGISTRATION", &mut config.registration_endpoint),
+            ("SENSORY", &mut config.sensory_endpoint),
+            ("MOTOR", &mut config.motor_endpoint),
+            ("VISUALIZATION", &mut config.visualization_endpoint),
+            ("CONTROL", &mut config.control_endpoint),
+        ];
+        for (name, endpoint) in endpoints {
+            let port_name = format!("{}_PORT", name);
+            let port = env_value::<u16>(&format!("{}{}", prefix, port_name))?;
+            if port.is_some() {
+                take(&port_name);
+            }
+            // Endpoints that are not configured stay that way, unless given a port
+            if port.is_some() || (host.is_some() && !endpoint.is_empty()) {
+                *endpoint = rewrite_endpoint(endpoint, host.as_deref(), port).ok_or_else(|| invalid_config(format!(
+                    "Cannot set the port of the {} endpoint: {}HOST is not set and the endpoint is '{}'",
+                    name.to_lowercase(), prefix, endpoint
+                )))?;
+            }
+            let endpoint_name = format!("{}_ENDPOINT", name);
+            if let Some(value) = env_string(&format!("{}{}", prefix, endpoint_name))? {
+                *endpoint = value;
+                take(&endpoint_name);
+            }
+        }
+        
+        if let Some(interval) = env_value(&format!("{}HEARTBEAT_INTERVAL", prefix))? {
+            config.heartbeat_interval = interval;
+            take("HEARTBEAT_INTERVAL");
+        }
+        if let Some(timeout) = env_value(&format!("{}CONNECTION_TIMEOUT_MS", prefix))? {
+            config.connection_timeout_ms = timeout;
+            take("CONNECTION_TIMEOUT_MS");
+        }
+        if let Some(retries) = env_value(&format!("{}REGISTRATION_RETRIES", prefix))? {
+            config.registration_retries = retries;
+            take("REGISTRATION_RETRIES");
+        }
+        if let Some(backoff) = env_value(&format!("{}RETRY_BACKOFF_MS", prefix))? {
+            config.retry_backoff_ms = backoff;
+            take("RETRY_BACKOFF_MS");
+        }
+        if let Some(hwm) = env_value(&format!("{}SENSORY_SEND_HWM", prefix))? {
+            config.sensory_send_hwm = hwm;
+            take("SENSORY_SEND_HWM");
+        }
+        if let Some(linger_ms) = env_value(&format!("{}SENSORY_LINGER_MS", prefix))? {
+            config.sensory_linger_ms = linger_ms;
+            take("SENSORY_LINGER_MS");
+        }
+        if let Some(immediate) = env_bool(&format!("{}SENSORY_IMMEDIATE", prefix))? {
+            config.sensory_immediate = immediate;
+            take("SENSORY_IMMEDIATE");
+        }
+        
+        // Nothing is changed if any variable is invalid
+        self.inner = config;
+        Ok(applied)
+    }
+    
+    /// Export the full effective configuration as JSON
+    #[pyo3(signature = (pretty = false))]
+    fn to_json(&self, pretty: bool) -> PyResult<String> {
+        let document = AgentConfigDocument::from_config(&self.inner);
+        let json = if pretty { serde_json::to_string_pretty(&document) } else { serde_json::to_string(&document) };
+        json.map_err(|e| PyAgentSdkError::from(feagi_agent::SdkError::from(e)).into())
+    }
+    
+    /// Export the full effective configuration as TOML
+    fn to_toml(&self) -> PyResult<String> {
+        AgentConfigDocument::from_config(&self.inner).to_toml()
+    }
+    
+    /// Export the full effective configuration as a dict
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        py.import("json")?.getattr("loads")?.call1((self.to_json(false)?,))
+    }
+    
+    #[getter]
+    fn agent_id(&self) -> String {
+        self.inner.agent_id.clone()
+    }
+    
+    #[getter]
+    fn agent_type(&self) -> PyAgentType {
+        PyAgentType::from(self.inner.agent_type)
+    }
+    
+    #[getter]
+    fn registration_endpoint(&self) -> String {
+        self.inner.registration_endpoint.clone()
+    }
+    
+    #[getter]
+    fn sensory_endpoint(&self) -> String {
+        self.inner.sensory_endpoint.clone()
+    }
+    
+    #[getter]
+    fn motor_endpoint(&self) -> String {
+        self.inner.motor_endpoint.clone()
+    }
+    
+    #[getter]
+    fn visualization_endpoint(&self) -> String {
+        self.inner.visualization_endpoint.clone()
+    }
+    
+    #[getter]
+    fn control_endpoint(&self) -> String {
+        self.inner.control_endpoint.clone()
+    }
+    
+    #[getter]
+    fn heartbeat_interval(&self) -> f64 {
+        self.inner.heartbeat_interval
+    }
+    
+    #[getter]
+    fn connection_timeout_ms(&self) -> u64 {
+        self.inner.connection_timeout_ms
+    }
+    
+    #[getter]
+    fn registration_retries(&self) -> u32 {
+        self.inner.registration_retries
+    }
+    
+    #[getter]
+    fn retry_backoff_ms(&self) -> u64 {
+        self.inner.retry_backoff_ms
+    }
+    
+    #[getter]
+    fn sensory_send_hwm(&self) -> i32 {
+        self.inner.sensory_send_hwm
+    }
+    
+    #[getter]
+    fn sensory_linger_ms(&self) -> i32 {
+        self.inner.sensory_linger_ms
+    }
+    
+    #[getter]
+    fn sensory_immediate(&self) -> bool {
+        self.inner.sensory_immediate
+    }
+    
+    /// Declared capabilities as a dict
+    #[getter]
+    fn capabilities<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let json = serde_json::to_string(&self.inner.capabilities)
+            .map_err(|e| PyAgentSdkError::from(feagi_agent::SdkError::from(e)))?;
+        py.import("json")?.getattr("loads")?.call1((json,))
+    }
+    
     fn __repr__(&self) -> String {
         format!("PyAgentConfig(agent_id={})", self.inner.agent_id)
     }
@@ -133,9 +492,33 @@ impl PyAgentConfig {
         &self.inner
     }
     
+    pub(crate) fn inner_mut(&mut self) -> &mut feagi_agent::AgentConfig {
+        &mut self.inner
+    }
+    
     #[allow(dead_code)]
     pub fn into_inner(self) -> feagi_agent::AgentConfig {
         self.inner
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document_json(config: &feagi_agent::AgentConfig) -> serde_json::Value {
+        serde_json::to_value(AgentConfigDocument::from_config(config)).unwrap()
+    }
+
+    #[test]
+    fn toml_round_trips_configs_with_unset_capabilities() {
+        let config = feagi_agent::AgentConfig::new("toml-agent", feagi_agent::AgentType::Visualization)
+            .with_motor_capability("servo", 2, vec!["omot00".to_string()])
+            .with_sensory_capability(30.0, None);
+        assert!(document_json(&config)["capabilities"]["vision"].is_null());
+
+        let toml = AgentConfigDocument::from_config(&config).to_toml().unwrap();
+        let document: AgentConfigDocument = toml::from_str(&toml).unwrap();
+        assert_eq!(document_json(&document.into_config().unwrap()), document_json(&config));
+    }
+}
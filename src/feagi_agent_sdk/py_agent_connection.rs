@@ -0,0 +1,408 @@
+/*
+ * Connection state machine and lifecycle events of PyAgentClient
+ *
+ * Once registered, a supervisor thread sends the heartbeats (on its own socket to the registration
+ * endpoint) in place of the underlying client, so heartbeat loss and FEAGI restarts become visible
+ * as state transitions, and re-registers the agent when FEAGI no longer knows it. The heartbeat service of
+ * feagi_agent does not report the outcome of its heartbeats, so the supervisor sends its own, interpreting
+ * the replies the same way
+ */
+
+use pyo3::prelude::*;
+use super::py_agent_client::PyAgentClient;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Most lifecycle events kept for get_events; older ones are discarded
+const MAX_QUEUED_EVENTS: usize = 256;
+
+/// Longest time the supervisor sleeps without checking whether it should stop
+const STOP_CHECK_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Connection state of a PyAgentClient
+#[pyclass(eq, eq_int)]
+#[derive(PartialEq, Clone, Copy, Debug)]
+#[pyo3(name = "ConnectionState")]
+pub enum PyConnectionState {
+    /// Not connected, or disconnected
+    Disconnected,
+    /// Registering with FEAGI
+    Connecting,
+    /// Registered, and FEAGI acknowledges heartbeats
+    Registered,
+    /// FEAGI stopped acknowledging heartbeats
+    HeartbeatLost,
+    /// FEAGI no longer knows the agent (such as after a restart), registering again
+    Reconnecting,
+}
+
+/// A transition between two connection states
+#[pyclass(name = "ConnectionEvent")]
+#[derive(Clone)]
+pub struct PyConnectionEvent {
+    #[pyo3(get)]
+    pub previous_state: PyConnectionState,
+    #[pyo3(get)]
+    pub state: PyConnectionState,
+    /// Why the transition happened, such as the error that caused it
+    #[pyo3(get)]
+    pub reason: Option<String>,
+    /// Unix time of the transition, in seconds
+    #[pyo3(get)]
+    pub timestamp: f64,
+}
+
+#[pymethods]
+impl PyConnectionEvent {
+    fn __repr__(&self) -> String {
+        format!(
+            "ConnectionEvent({:?} -> {:?}, reason={})",
+            self.previous_state, self.state, self.reason.as_deref().map_or("None".to_string(), |reason| format!("'{}'", reason))
+        )
+    }
+}
+
+struct MonitorState {
+    state: PyConnectionState,
+    events: VecDeque<PyConnectionEvent>,
+}
+
+/// Connection state shared between the client and its supervisor thread
+pub(crate) struct ConnectionMonitor {
+    state: Mutex<MonitorState>,
+    state_changed: Condvar,
+    listeners: Mutex<Vec<Py<PyAny>>>,
+}
+
+impl ConnectionMonitor {
+    pub(crate) fn new() -> Self {
+        ConnectionMonitor {
+            state: Mutex::new(MonitorState { state: PyConnectionState::Disconnected, events: VecDeque::new() }),
+            state_changed: Condvar::new(),
+            listeners: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MonitorState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub(crate) fn state(&self) -> PyConnectionState {
+        self.lock().state
+    }
+
+    /// Moves to the given state, queueing an event and notifying the listeners. Does nothing if already in it.
+    /// Listeners are called on the calling thread, which must not hold the GIL while blocked on this client
+    pub(crate) fn transition(&self, state: PyConnectionState, reason: Option<String>) {
+        let event = {
+            let mut monitor_state = self.lock();
+            if monitor_state.state == state {
+                return;
+            }
+            let event = PyConnectionEvent {
+                previous_state: monitor_state.state,
+                state,
+                reason,
+                timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_secs_f64()).unwrap_or_default(),
+            };
+            monitor_state.state = state;
+            if monitor_state.events.len() == MAX_QUEUED_EVENTS {
+                monitor_state.events.pop_front();
+            }
+            monitor_state.events.push_back(event.clone());
+            event
+        };
+        self.state_changed.notify_all();
+        tracing::info!("[CLIENT] Connection state {:?} -> {:?}", event.previous_state, event.state);
+
+        if self.listeners.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).is_empty() {
+            return;
+        }
+        Python::attach(|py| {
+            let listeners: Vec<Py<PyAny>> = self.listeners.lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .iter()
+                .map(|listener| listener.clone_ref(py))
+                .collect();
+            for listener in listeners {
+                if let Err(e) = listener.call1(py, (event.clone(),)) {
+                    e.write_unraisable(py, Some(listener.bind(py)));
+                }
+            }
+        });
+    }
+}
+
+/// Heartbeats on behalf of a registered client and re-registers it when FEAGI no longer knows it
+pub(crate) struct ConnectionSupervisor {
+    running: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ConnectionSupervisor {
+    pub(crate) fn start(config: &feagi_agent::AgentConfig, monitor: Arc<ConnectionMonitor>) -> Result<Self, feagi_agent::SdkError> {
+        let socket = zmq::Context::new().socket(zmq::REQ)?;
+        socket.set_linger(0)?;
+        socket.set_rcvtimeo(config.connection_timeout_ms as i32)?;
+        socket.set_sndtimeo(config.connection_timeout_ms as i32)?;
+        // A lost heartbeat reply must not block the next heartbeat
+        socket.set_req_relaxed(true)?;
+        socket.set_req_correlate(true)?;
+        socket.connect(&config.registration_endpoint)?;
+
+        let running = Arc::new(AtomicBool::new(true));
+        let supervisor = Supervisor {
+            socket,
+            config: config.clone(),
+            monitor,
+            running: Arc::clone(&running),
+        };
+        let thread = std::thread::Builder::new()
+            .name("feagi-agent-supervisor".to_string())
+            .spawn(move || supervisor.run())
+            .map_err(|e| feagi_agent::SdkError::ThreadError(e.to_string()))?;
+        Ok(ConnectionSupervisor { running, thread: Some(thread) })
+    }
+
+    /// Stops the supervisor and waits for it. Call without holding the GIL, as the supervisor may need it for listeners
+    pub(crate) fn stop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for ConnectionSupervisor {
+    fn drop(&mut self) {
+        // The thread is not joined here, as the dropping thread may hold the GIL. It exits on its own
+        self.running.store(false, Ordering::Relaxed);
+    }
+}
+
+/// Outcome of a heartbeat
+enum HeartbeatOutcome {
+    Acknowledged,
+    NotRegistered,
+    Failed(String),
+}
+
+struct Supervisor {
+    socket: zmq::Socket,
+    config: feagi_agent::AgentConfig,
+    monitor: Arc<ConnectionMonitor>,
+    running: Arc<AtomicBool>,
+}
+
+impl Supervisor {
+    fn run(self) {
+        let interval = Duration::from_secs_f64(self.config.heartbeat_interval);
+        while self.sleep(interval) {
+            let outcome = self.heartbeat();
+            if !self.running.load(Ordering::Relaxed) {
+                break;
+            }
+            match outcome {
+                HeartbeatOutcome::Acknowledged => {
+                    self.monitor.transition(PyConnectionState::Registered, Some("Heartbeat acknowledged".to_string()));
+                }
+                HeartbeatOutcome::NotRegistered => {
+                    self.monitor.transition(PyConnectionState::Reconnecting, Some("FEAGI no longer knows the agent".to_string()));
+                    match self.register() {
+                        Ok(()) => self.monitor.transition(PyConnectionState::Registered, Some("Registered again".to_string())),
+                        Err(e) => tracing::warn!("[CLIENT] Re-registration of {} failed: {}", self.config.agent_id, e),
+                    }
+                }
+                HeartbeatOutcome::Failed(reason) => {
+                    // A heartbeat failing while reconnecting does not mean FEAGI knows the agent again
+                    if self.monitor.state() == PyConnectionState::Registered {
+                        self.monitor.transition(PyConnectionState::HeartbeatLost, Some(reason));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Sleeps for the given duration. Returns false if the supervisor was stopped meanwhile
+    fn sleep(&self, duration: Duration) -> bool {
+        let deadline = Instant::now() + duration;
+        while self.running.load(Ordering::Relaxed) {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return true;
+            }
+            std::thread::sleep(remaining.min(STOP_CHECK_INTERVAL));
+        }
+        false
+    }
+
+    /// Sends a request on the registration channel and returns the reply
+    fn request(&self, request: serde_json::Value) -> Result<serde_json::Value, feagi_agent::SdkError> {
+        self.socket.send(request.to_string().as_bytes(), 0)?;
+        let reply = self.socket.recv_bytes(0).map_err(|e| match e {
+            zmq::Error::EAGAIN => feagi_agent::SdkError::Timeout("No reply from FEAGI".to_string()),
+            e => e.into(),
+        })?;
+        Ok(serde_json::from_slice(&reply)?)
+    }
+
+    fn heartbeat(&self) -> HeartbeatOutcome {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_millis() as u64).unwrap_or_default();
+        let reply = match self.request(serde_json::json!({
+            "method": "POST",
+            "path": "/v1/agent/heartbeat",
+            "body": {"agent_id": self.config.agent_id, "timestamp": timestamp},
+        })) {
+            Ok(reply) => reply,
+            Err(e) => return HeartbeatOutcome::Failed(e.to_string()),
+        };
+
+        if is_success_reply(&reply) {
+            HeartbeatOutcome::Acknowledged
+        } else if is_agent_not_registered_reply(&reply) {
+            HeartbeatOutcome::NotRegistered
+        } else {
+            HeartbeatOutcome::Failed(format!("Heartbeat rejected: {}", reply))
+        }
+    }
+
+    fn register(&self) -> Result<(), feagi_agent::SdkError> {
+        let reply = self.request(serde_json::json!({
+            "method": "POST",
+            "path": "/v1/agent/register",
+            "body": {
+                "agent_id": self.config.agent_id,
+                "agent_type": self.config.agent_type.to_string(),
+                "capabilities": self.config.capabilities,
+            }
+        }))?;
+        if is_success_reply(&reply) {
+            return Ok(());
+        }
+        Err(feagi_agent::SdkError::RegistrationFailed(reply.to_string()))
+    }
+}
+
+/// Whether a reply on the registration channel reports success, accepting the same statuses as the heartbeat
+/// service of the underlying client: {"status": "success"} (or "ok") from older FEAGI versions, or {"status": 200}
+fn is_success_reply(reply: &serde_json::Value) -> bool {
+    match reply.get("status") {
+        Some(serde_json::Value::String(status)) => status == "success" || status == "ok",
+        Some(serde_json::Value::Number(status)) => status.as_u64() == Some(200),
+        _ => false,
+    }
+}
+
+/// Whether a rejected heartbeat means FEAGI no longer knows the agent (a 404 naming it not found), as the heartbeat
+/// service of the underlying client detects it
+fn is_agent_not_registered_reply(reply: &serde_json::Value) -> bool {
+    reply.get("status").and_then(serde_json::Value::as_u64) == Some(404)
+        && reply.get("body")
+            .and_then(|body| body.get("error"))
+            .and_then(serde_json::Value::as_str)
+            .is_some_and(|error| error.contains("not found"))
+}
+
+#[pymethods]
+impl PyAgentClient {
+    #[getter]
+    fn connection_state(&self) -> PyConnectionState {
+        self.connection_monitor().state()
+    }
+
+    /// Call the given callable with every ConnectionEvent from now on. Callbacks may run on a background thread
+    fn add_connection_listener(&self, callback: Py<PyAny>) {
+        self.connection_monitor().listeners.lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(callback);
+    }
+
+    /// Stop calling the given callable. Returns False if it was not a listener
+    fn remove_connection_listener(&self, py: Python<'_>, callback: Py<PyAny>) -> bool {
+        let mut listeners = self.connection_monitor().listeners.lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let count = listeners.len();
+        listeners.retain(|listener| !listener.bind(py).is(callback.bind(py)));
+        listeners.len() != count
+    }
+
+    /// Take the queued ConnectionEvents, oldest first
+    fn get_events(&self) -> Vec<PyConnectionEvent> {
+        self.connection_monitor().lock().events.drain(..).collect()
+    }
+
+    /// Wait until the client is in the given state, up to timeout seconds (forever if None). Returns False on timeout
+    #[pyo3(signature = (state, timeout = Some(5.0)))]
+    fn wait_for_state(&self, py: Python<'_>, state: PyConnectionState, timeout: Option<f64>) -> PyResult<bool> {
+        let monitor = self.connection_monitor();
+        let deadline = timeout.map(|timeout| Instant::now() + Duration::from_secs_f64(timeout.max(0.0)));
+        loop {
+            let reached = py.detach(|| {
+                let monitor_state = monitor.lock();
+                let wait = deadline.map_or(STOP_CHECK_INTERVAL, |deadline| {
+                    deadline.saturating_duration_since(Instant::now()).min(STOP_CHECK_INTERVAL)
+                });
+                let (monitor_state, _) = monitor.state_changed
+                    .wait_timeout_while(monitor_state, wait, |monitor_state| monitor_state.state != state)
+                    .unwrap_or_else(|poisoned| poisoned.into_inner());
+                monitor_state.state == state
+            });
+            if reached {
+                return Ok(true);
+            }
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                return Ok(false);
+            }
+            py.check_signals()?;
+        }
+    }
+
+    fn __enter__(mut slf: PyRefMut<'_, Self>) -> PyResult<PyRefMut<'_, Self>> {
+        if slf.connection_monitor().state() == PyConnectionState::Disconnected {
+            let py = slf.py();
+            slf.connect(py)?;
+        }
+        Ok(slf)
+    }
+
+    fn __exit__(&mut self, py: Python<'_>, _exc_type: Py<PyAny>, _exc_value: Py<PyAny>, _traceback: Py<PyAny>) -> PyResult<bool> {
+        self.disconnect(py)?;
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::feagi_agent_sdk::py_mock_feagi_server::testing::run_against_mock_server;
+
+    #[test]
+    fn supervisor_registers_again_after_feagi_forgets_the_agent() {
+        run_against_mock_server(cr#"
+config = agent_config('supervised')
+config.with_heartbeat_interval(0.05)
+client = agent.PyAgentClient(config)
+client.connect()
+assert client.wait_for_state(agent.ConnectionState.Registered)
+
+send_sensory_until_received(lambda: client.send_sensory_bytes(b'before restart'))
+
+# FEAGI restarting forgets the agent, which registers again once its heartbeat is rejected
+client.get_events()
+server.forget_agent('supervised')
+assert server.wait_for_registration('supervised')
+assert client.wait_for_state(agent.ConnectionState.Registered)
+states = [event.state for event in client.get_events()]
+assert states == [agent.ConnectionState.Reconnecting, agent.ConnectionState.Registered], states
+
+send_sensory_until_received(lambda: client.send_sensory_bytes(b'after restart'))
+assert publish_motor_until(motor_payload(), client.receive_motor_data) is not None
+
+client.disconnect()
+assert client.connection_state == agent.ConnectionState.Disconnected
+assert server.registered_agents == []
+"#);
+    }
+}
@@ -0,0 +1,294 @@
+/*
+ * Control channel of PyAgentClient: FEAGI's REST API over ZMQ (REQ socket on the control endpoint)
+ */
+
+use pyo3::prelude::*;
+use serde_json::Value;
+use super::py_agent_client::PyAgentClient;
+use crate::py_error::{PyAgentSdkError, PyHttpStatusError};
+use std::sync::Mutex;
+
+// FEAGI API routes used by the typed control operations
+const ROUTE_SIMULATION_TIMESTEP: &str = "/v1/burst_engine/simulation_timestep";
+const ROUTE_BURST_ENGINE_CONTROL: &str = "/v1/burst_engine/control";
+const ROUTE_AGENT_LIST: &str = "/v1/agent/list";
+const ROUTE_GENOME_METADATA: &str = "/v1/genome/metadata";
+
+/// Lazily connected REQ socket to FEAGI's control endpoint
+pub(crate) struct ControlChannel {
+    endpoint: String,
+    timeout_ms: i32,
+    socket: Mutex<Option<zmq::Socket>>,
+}
+
+impl ControlChannel {
+    pub(crate) fn new(config: &feagi_agent::AgentConfig) -> Self {
+        ControlChannel {
+            endpoint: config.control_endpoint.clone(),
+            timeout_ms: config.connection_timeout_ms as i32,
+            socket: Mutex::new(None),
+        }
+    }
+
+    fn connect(&self) -> Result<zmq::Socket, feagi_agent::SdkError> {
+        if self.endpoint.is_empty() {
+            return Err(feagi_agent::SdkError::InvalidConfig("Control endpoint is not set".to_string()));
+        }
+        let socket = zmq::Context::new().socket(zmq::REQ)?;
+        socket.set_linger(0)?;
+        socket.set_rcvtimeo(self.timeout_ms)?;
+        socket.set_sndtimeo(self.timeout_ms)?;
+        // A timed out request must not leave the socket waiting for its reply forever
+        socket.set_req_relaxed(true)?;
+        socket.set_req_correlate(true)?;
+        socket.connect(&self.endpoint)?;
+        Ok(socket)
+    }
+
+    /// Sends a request and returns the (status, body) of the reply
+    fn request(&self, method: &str, route: &str, body: Option<Value>) -> Result<(u16, Value), feagi_agent::SdkError> {
+        let mut request = serde_json::json!({
+            "method": method,
+            "route": route,
+            "headers": {"content-type": "application/json"},
+        });
+        if let Some(body) = body {
+            request["body"] = body;
+        }
+
+        let mut socket = self.socket.lock()
+            .map_err(|e| feagi_agent::SdkError::ThreadError(format!("Lock poisoned: {}", e)))?;
+        let control_socket = match socket.take() {
+            Some(control_socket) => control_socket,
+            None => self.connect()?,
+        };
+
+        let reply = control_socket.send(request.to_string().as_bytes(), 0)
+            .and_then(|_| control_socket.recv_bytes(0));
+        let reply = match reply {
+            Ok(reply) => reply,
+            Err(zmq::Error::EAGAIN) => {
+                *socket = Some(control_socket);
+                return Err(feagi_agent::SdkError::Timeout(format!("No reply to {} {} within {} ms", method, route, self.timeout_ms)));
+            }
+            // The socket is dropped, so the next request starts over with a fresh one
+            Err(e) => return Err(e.into()),
+        };
+        *socket = Some(control_socket);
+
+        let reply: Value = serde_json::from_slice(&reply)?;
+        Ok(parse_reply(reply))
+    }
+}
+
+/// Splits a REST over ZMQ reply into its status and body. Replies are either {"status": ..., "body": ...}
+/// or a bare body, whose status is then assumed to be 200
+fn parse_reply(mut reply: Value) -> (u16, Value) {
+    let status = match reply.get("status") {
+        Some(Value::Number(status)) => status.as_u64().map(|status| status as u16),
+        Some(Value::String(status)) if status == "success" || status == "ok" => Some(200),
+        Some(Value::String(_)) => Some(500),
+        _ => None,
+    };
+    match (status, reply.get_mut("body")) {
+        (Some(status), Some(body)) => (status, body.take()),
+        (Some(status), None) => (status, reply),
+        (None, _) => (200, reply),
+    }
+}
+
+/// Message of an error reply
+fn error_message(body: &Value) -> String {
+    match body {
+        Value::String(message) => message.clone(),
+        Value::Object(object) => object.get("error")
+            .or_else(|| object.get("detail"))
+            .or_else(|| object.get("message"))
+            .map(|message| message.as_str().map(str::to_string).unwrap_or_else(|| message.to_string()))
+            .unwrap_or_else(|| body.to_string()),
+        _ => body.to_string(),
+    }
+}
+
+/// Unwraps a value that FEAGI returns either bare or as the single field of an object
+fn field_or_value<'a>(body: &'a Value, key: &str) -> &'a Value {
+    body.get(key).unwrap_or(body)
+}
+
+fn unexpected_reply(route: &str, body: &Value) -> PyErr {
+    PyAgentSdkError::from(feagi_agent::SdkError::Other(format!("Unexpected reply to {}: {}", route, body))).into()
+}
+
+/// Metadata of the genome loaded in FEAGI
+#[pyclass(name = "GenomeMetadata")]
+#[derive(Clone)]
+pub struct PyGenomeMetadata {
+    #[pyo3(get)]
+    pub genome_id: Option<String>,
+    #[pyo3(get)]
+    pub genome_title: Option<String>,
+    #[pyo3(get)]
+    pub genome_description: Option<String>,
+    #[pyo3(get)]
+    pub version: Option<String>,
+    #[pyo3(get)]
+    pub cortical_area_count: Option<usize>,
+    /// The full reply as JSON, including fields not listed above
+    #[pyo3(get)]
+    pub json: String,
+}
+
+impl PyGenomeMetadata {
+    fn from_body(body: &Value) -> Self {
+        let string_of = |keys: &[&str]| keys.iter()
+            .find_map(|key| body.get(*key))
+            .map(|value| value.as_str().map(str::to_string).unwrap_or_else(|| value.to_string()));
+        PyGenomeMetadata {
+            genome_id: string_of(&["genome_id"]),
+            genome_title: string_of(&["genome_title", "title"]),
+            genome_description: string_of(&["genome_description", "description"]),
+            version: string_of(&["version", "genome_version"]),
+            cortical_area_count: body.get("cortical_area_count").and_then(Value::as_u64).map(|count| count as usize),
+            json: body.to_string(),
+        }
+    }
+}
+
+#[pymethods]
+impl PyGenomeMetadata {
+    fn __repr__(&self) -> String {
+        let repr_of = |value: &Option<String>| value.as_ref().map_or("None".to_string(), |value| format!("'{}'", value));
+        format!(
+            "GenomeMetadata(genome_id={}, genome_title={}, version={})",
+            repr_of(&self.genome_id), repr_of(&self.genome_title), repr_of(&self.version)
+        )
+    }
+}
+
+impl PyAgentClient {
+    /// Sends a control request without holding the GIL, raising if FEAGI replies with an error status
+    fn control_call(&self, py: Python<'_>, method: &str, route: &str, body: Option<Value>) -> PyResult<Value> {
+        let control_channel = self.control_channel();
+        let (status, body) = py.detach(|| control_channel.request(method, route, body))
+            .map_err(PyAgentSdkError::from)?;
+        if status >= 400 {
+            return Err(PyHttpStatusError {
+                method: method.to_string(),
+                route: route.to_string(),
+                status,
+                body: body.to_string(),
+                message: error_message(&body),
+            }.into());
+        }
+        Ok(body)
+    }
+}
+
+#[pymethods]
+impl PyAgentClient {
+    /// Send a request to the FEAGI API over the control channel. Does not require registration
+    ///
+    /// # Arguments
+    /// * `method` - HTTP method (GET, POST, PUT, DELETE)
+    /// * `route` - API route (e.g., "/v1/system/health_check")
+    /// * `body_json` - Optional request body as JSON
+    ///
+    /// # Returns
+    /// * `str` - Body of the reply as JSON. Error statuses raise HttpError instead
+    #[pyo3(signature = (method, route, body_json = None))]
+    fn control_request(&self, py: Python<'_>, method: &str, route: &str, body_json: Option<&str>) -> PyResult<String> {
+        let body = body_json
+            .map(serde_json::from_str::<Value>)
+            .transpose()
+            .map_err(|e| PyAgentSdkError::from(feagi_agent::SdkError::from(e)))?;
+        Ok(self.control_call(py, &method.to_uppercase(), route, body)?.to_string())
+    }
+
+    /// Current burst rate of the burst engine in Hz
+    fn get_burst_rate(&self, py: Python<'_>) -> PyResult<f64> {
+        let body = self.control_call(py, "GET", ROUTE_SIMULATION_TIMESTEP, None)?;
+        let timestep = field_or_value(&body, "simulation_timestep").as_f64()
+            .filter(|timestep| *timestep > 0.0)
+            .ok_or_else(|| unexpected_reply(ROUTE_SIMULATION_TIMESTEP, &body))?;
+        Ok(1.0 / timestep)
+    }
+
+    /// Set the burst rate of the burst engine in Hz
+    fn set_burst_rate(&self, py: Python<'_>, burst_rate_hz: f64) -> PyResult<()> {
+        if !(burst_rate_hz.is_finite() && burst_rate_hz > 0.0) {
+            return Err(PyAgentSdkError::from(feagi_agent::SdkError::InvalidConfig(
+                format!("Burst rate must be a positive number of Hz, got {}", burst_rate_hz)
+            )).into());
+        }
+        let body = serde_json::json!({"simulation_timestep": 1.0 / burst_rate_hz});
+        self.control_call(py, "POST", ROUTE_SIMULATION_TIMESTEP, Some(body))?;
+        Ok(())
+    }
+
+    /// Pause the burst engine
+    fn pause_burst_engine(&self, py: Python<'_>) -> PyResult<()> {
+        self.control_call(py, "POST", ROUTE_BURST_ENGINE_CONTROL, Some(serde_json::json!({"action": "pause"})))?;
+        Ok(())
+    }
+
+    /// Resume a paused burst engine
+    fn resume_burst_engine(&self, py: Python<'_>) -> PyResult<()> {
+        self.control_call(py, "POST", ROUTE_BURST_ENGINE_CONTROL, Some(serde_json::json!({"action": "resume"})))?;
+        Ok(())
+    }
+
+    /// IDs of the agents registered with FEAGI
+    fn get_registered_agents(&self, py: Python<'_>) -> PyResult<Vec<String>> {
+        let body = self.control_call(py, "GET", ROUTE_AGENT_LIST, None)?;
+        match field_or_value(&body, "agents") {
+            Value::Array(agents) => agents.iter()
+                .map(|agent| field_or_value(agent, "agent_id").as_str().map(str::to_string))
+                .collect::<Option<Vec<String>>>()
+                .ok_or_else(|| unexpected_reply(ROUTE_AGENT_LIST, &body)),
+            Value::Object(agents) => Ok(agents.keys().cloned().collect()),
+            _ => Err(unexpected_reply(ROUTE_AGENT_LIST, &body)),
+        }
+    }
+
+    /// Metadata of the genome loaded in FEAGI
+    fn get_genome_metadata(&self, py: Python<'_>) -> PyResult<PyGenomeMetadata> {
+        let body = self.control_call(py, "GET", ROUTE_GENOME_METADATA, None)?;
+        if !body.is_object() {
+            return Err(unexpected_reply(ROUTE_GENOME_METADATA, &body));
+        }
+        Ok(PyGenomeMetadata::from_body(&body))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::feagi_agent_sdk::py_mock_feagi_server::testing::run_against_mock_server;
+
+    #[test]
+    fn control_requests_reach_the_server_of_a_registered_agent() {
+        run_against_mock_server(cr#"
+client = agent.PyAgentClient(agent_config('control'))
+client.connect()
+assert 'control' in client.get_registered_agents()
+
+send_sensory_until_received(lambda: client.send_sensory_bytes(b'sensory'))
+assert publish_motor_until(motor_payload(), client.receive_motor_data) is not None
+
+client.pause_burst_engine()
+assert server.burst_engine_paused
+client.resume_burst_engine()
+assert not server.burst_engine_paused
+client.set_burst_rate(20.0)
+assert abs(client.get_burst_rate() - 20.0) < 1e-9
+assert client.get_genome_metadata().genome_id == 'mock_genome'
+
+server.set_control_response('GET', '/v1/genome/metadata', 503, '{"error": "no genome"}')
+try:
+    client.get_genome_metadata()
+    raise AssertionError('HttpError expected')
+except feagi.HttpError as error:
+    assert error.status == 503 and error.retryable
+client.disconnect()
+"#);
+    }
+}
@@ -0,0 +1,291 @@
+/*
+ * PyO3 runtime joining a ConnectorAgent with an AgentClient
+ *
+ * Runs the encode -> send -> receive -> decode cycle on the Rust side
+ */
+
+use pyo3::prelude::*;
+use super::py_agent_client::PyAgentClient;
+use crate::feagi_connector_core::{PyConnectorAgent, SensorByteExport, TrafficDirection, TrafficRecorderSlot};
+use crate::py_error::{PyAgentSdkError, PyFeagiError};
+use feagi_agent::SdkError;
+use feagi_data_structures::FeagiDataError;
+use feagi_serialization::FeagiByteContainer;
+use feagi_sensorimotor::caching::{MotorDeviceCache, SensorDeviceCache};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// State shared between the Python facing runtime and its background thread
+struct AgentRuntimeShared {
+    sensor_cache: Arc<Mutex<SensorDeviceCache>>,
+    motor_cache: Arc<Mutex<MotorDeviceCache>>,
+    sensor_byte_export: SensorByteExport,
+    traffic_recorder: TrafficRecorderSlot,
+    client: Arc<Mutex<feagi_agent::AgentClient>>,
+    receives_motor_data: bool,
+    running: AtomicBool,
+    cycle_count: AtomicU64,
+    last_error: Mutex<Option<String>>,
+}
+
+/// Error of a runtime cycle, converted to the matching Python exception once back in Python
+#[derive(Debug)]
+enum RuntimeCycleError {
+    Data(FeagiDataError),
+    Sdk(SdkError),
+    /// Raised by Python code run during the cycle, such as the traffic recorder
+    Python(PyErr),
+}
+
+impl fmt::Display for RuntimeCycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuntimeCycleError::Data(e) => write!(f, "{}", e),
+            RuntimeCycleError::Sdk(e) => write!(f, "{}", e),
+            RuntimeCycleError::Python(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<FeagiDataError> for RuntimeCycleError {
+    fn from(error: FeagiDataError) -> Self {
+        RuntimeCycleError::Data(error)
+    }
+}
+
+impl From<SdkError> for RuntimeCycleError {
+    fn from(error: SdkError) -> Self {
+        RuntimeCycleError::Sdk(error)
+    }
+}
+
+impl From<PyErr> for RuntimeCycleError {
+    fn from(error: PyErr) -> Self {
+        RuntimeCycleError::Python(error)
+    }
+}
+
+impl From<RuntimeCycleError> for PyErr {
+    fn from(error: RuntimeCycleError) -> PyErr {
+        match error {
+            RuntimeCycleError::Data(e) => PyFeagiError::from(e).into(),
+            RuntimeCycleError::Sdk(e) => PyAgentSdkError::from(e).into(),
+            RuntimeCycleError::Python(e) => e,
+        }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> Result<MutexGuard<'_, T>, SdkError> {
+    mutex.lock().map_err(|e| SdkError::ThreadError(format!("Lock poisoned: {}", e)))
+}
+
+impl AgentRuntimeShared {
+    /// Runs a single encode -> send -> receive -> decode cycle. Returns true if motor data was received.
+    /// Must be called without holding the GIL.
+    fn run_cycle(&self) -> Result<bool, RuntimeCycleError> {
+        let recording = self.traffic_recorder.is_attached()?;
+
+        // Encode and send sensory data. The send drops the payload on backpressure instead of blocking
+        let sent_sensory_data = {
+            let mut sensor_cache = lock(&self.sensor_cache)?;
+            {
+                let _sensor_bytes_write = self.sensor_byte_export.begin_write()?;
+                sensor_cache.encode_all_sensors_to_neurons(Instant::now())?;
+                sensor_cache.encode_neurons_to_bytes()?;
+            }
+
+            let client = lock(&self.client)?;
+            client.try_send_sensory_bytes(sensor_cache.get_feagi_byte_container().get_byte_ref())?;
+
+            // Recording needs the GIL, which cannot be taken while holding the sensor cache, so a copy is recorded
+            recording.then(|| sensor_cache.get_feagi_byte_container().clone())
+        };
+        if let Some(sent_sensory_data) = sent_sensory_data {
+            Python::attach(|py| self.traffic_recorder.record(py, TrafficDirection::Sensory, &sent_sensory_data))?;
+        }
+
+        if !self.receives_motor_data {
+            self.cycle_count.fetch_add(1, Ordering::Relaxed);
+            return Ok(false);
+        }
+
+        // Receive and decode every pending motor payload
+        let mut received_motor_data = false;
+        loop {
+            let motor_data = lock(&self.client)?.receive_motor_data()?;
+            let Some(motor_data) = motor_data else {
+                break;
+            };
+
+            // Motor callbacks may be Python callables, so the GIL is taken before the motor cache
+            // to keep the same lock order as calls coming from Python
+            Python::attach(|py| -> Result<(), RuntimeCycleError> {
+                if recording {
+                    // The client only hands out the decoded structure, so it is serialized back into a container
+                    let mut received_motor_data = FeagiByteContainer::new_empty();
+                    received_motor_data.overwrite_byte_data_with_single_struct_data(&motor_data, 0)?;
+                    self.traffic_recorder.record(py, TrafficDirection::Motor, &received_motor_data)?;
+                }
+                let mut motor_cache = lock(&self.motor_cache)?;
+                motor_cache.ingest_neuron_data_and_run_callbacks(motor_data, Instant::now())?;
+                Ok(())
+            })?;
+            received_motor_data = true;
+        }
+
+        self.cycle_count.fetch_add(1, Ordering::Relaxed);
+        Ok(received_motor_data)
+    }
+
+    fn run_loop(&self, burst_interval: Duration) {
+        while self.running.load(Ordering::Acquire) {
+            let cycle_start = Instant::now();
+            if let Err(e) = self.run_cycle() {
+                tracing::warn!("[RUNTIME] Agent runtime cycle failed: {}", e);
+                if let Ok(mut last_error) = self.last_error.lock() {
+                    *last_error = Some(e.to_string());
+                }
+            }
+            if let Some(remaining) = burst_interval.checked_sub(cycle_start.elapsed()) {
+                std::thread::sleep(remaining);
+            }
+        }
+    }
+}
+
+#[pyclass(name = "PyAgentRuntime")]
+pub struct PyAgentRuntime {
+    shared: Arc<AgentRuntimeShared>,
+    worker: Option<JoinHandle<()>>,
+}
+
+#[pymethods]
+impl PyAgentRuntime {
+    /// Create a runtime driving the given connector agent through the given (connected) agent client.
+    /// The connector agent stays usable from Python to write sensors and read motors. Payloads sent and
+    /// received by the runtime are recorded to the traffic recorder attached to the connector agent, if any.
+    #[new]
+    fn new(connector_agent: &PyConnectorAgent, client: &PyAgentClient) -> Self {
+        let shared = AgentRuntimeShared {
+            sensor_cache: connector_agent.inner.get_sensor_cache_ref(),
+            motor_cache: connector_agent.inner.get_motor_cache_ref(),
+            sensor_byte_export: connector_agent.sensor_byte_export.clone(),
+            traffic_recorder: connector_agent.traffic_recorder.clone(),
+            client: client.shared_inner(),
+            receives_motor_data: client.receives_motor_data(),
+            running: AtomicBool::new(false),
+            cycle_count: AtomicU64::new(0),
+            last_error: Mutex::new(None),
+        };
+        PyAgentRuntime {
+            shared: Arc::new(shared),
+            worker: None,
+        }
+    }
+
+    /// Run a single encode -> send -> receive -> decode cycle with the GIL released.
+    /// Returns True if motor data was received and decoded into the motor cache
+    fn step(&self, py: Python<'_>) -> PyResult<bool> {
+        if self.shared.running.load(Ordering::Acquire) {
+            return Err(PyAgentSdkError::from(SdkError::Other(
+                "Cannot step manually while the runtime is running".to_string()
+            )).into());
+        }
+        Ok(py.detach(|| self.shared.run_cycle())?)
+    }
+
+    /// Start running the cycle on a background thread, once every burst interval (in seconds)
+    fn start(&mut self, burst_interval_seconds: f64) -> PyResult<()> {
+        if !burst_interval_seconds.is_finite() || burst_interval_seconds <= 0.0 {
+            return Err(PyFeagiError::from(FeagiDataError::BadParameters(
+                format!("Burst interval must be a positive number of seconds, got {}", burst_interval_seconds)
+            )).into());
+        }
+        if self.shared.running.swap(true, Ordering::AcqRel) {
+            return Err(PyAgentSdkError::from(SdkError::Other(
+                "Runtime is already running".to_string()
+            )).into());
+        }
+
+        let shared = self.shared.clone();
+        let burst_interval = Duration::from_secs_f64(burst_interval_seconds);
+        let worker = std::thread::Builder::new()
+            .name("feagi-agent-runtime".to_string())
+            .spawn(move || shared.run_loop(burst_interval))
+            .map_err(|e| {
+                self.shared.running.store(false, Ordering::Release);
+                PyAgentSdkError::from(SdkError::ThreadError(format!("Failed to spawn runtime thread: {}", e)))
+            })?;
+        self.worker = Some(worker);
+        Ok(())
+    }
+
+    /// Stop the background thread and wait for the current cycle to finish. No-op if not running
+    fn stop(&mut self, py: Python<'_>) -> PyResult<()> {
+        self.shared.running.store(false, Ordering::Release);
+        if let Some(worker) = self.worker.take() {
+            // The worker may need the GIL to run motor callbacks, so it must be released while joining
+            py.detach(|| worker.join())
+                .map_err(|_| PyAgentSdkError::from(SdkError::ThreadError("Runtime thread panicked".to_string())))?;
+        }
+        Ok(())
+    }
+
+    /// Check if the background thread is running
+    fn is_running(&self) -> bool {
+        self.shared.running.load(Ordering::Acquire)
+    }
+
+    /// Number of cycles successfully completed
+    fn cycle_count(&self) -> u64 {
+        self.shared.cycle_count.load(Ordering::Relaxed)
+    }
+
+    /// Message of the last error raised by a background cycle, if any. Clears the stored error
+    fn take_last_error(&self) -> PyResult<Option<String>> {
+        let mut last_error = lock(&self.shared.last_error).map_err(PyAgentSdkError::from)?;
+        Ok(last_error.take())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("PyAgentRuntime(running={}, cycles={})", self.is_running(), self.cycle_count())
+    }
+}
+
+impl Drop for PyAgentRuntime {
+    fn drop(&mut self) {
+        // Signal the worker to exit but do not join, as the GIL may be held here
+        self.shared.running.store(false, Ordering::Release);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::feagi_agent_sdk::py_mock_feagi_server::testing::run_against_mock_server;
+
+    #[test]
+    fn runtime_round_trips_sensory_and_motor_data() {
+        run_against_mock_server(cr#"
+client = agent.PyAgentClient(agent_config('runtime'))
+client.connect()
+assert server.wait_for_registration('runtime')
+
+connector = feagi.connector_core.ConnectorAgent()
+connector.sensor_Infrared_register(0, 1, cortical_area.FrameChangeHandling.Absolute(), 10, cortical_area.PercentageNeuronPositioning.Linear())
+connector.motor_rotary_motor_register(0, 1, cortical_area.FrameChangeHandling.Absolute(), 10, cortical_area.PercentageNeuronPositioning.Linear())
+connector.sensor_infrared_write(0, 0, feagi.connector_core.data_types.Percentage.new_from_0_1(0.5))
+runtime = agent.PyAgentRuntime(connector, client)
+
+send_sensory_until_received(runtime.step)
+
+rotary_motor_id = connector.get_registered_motors()[0].cortical_ids[0]
+publish_motor_until(motor_payload(rotary_motor_id, z=9), runtime.step)
+assert connector.motor_rotary_motor_read_postprocessed_cache_value(0, 0).get_as_m1_1() > 0.0
+assert runtime.take_last_error() is None
+client.disconnect()
+"#);
+    }
+}
@@ -0,0 +1,476 @@
+/*
+ * Asyncio-native PyO3 wrapper for AgentClient
+ *
+ * All socket I/O runs on a dedicated Rust thread. Python receives asyncio futures,
+ * which the I/O thread completes through the event loop (call_soon_threadsafe).
+ * The thread sleeps in zmq::poll until motor data arrives, a receive times out, or Python wakes it up
+ * with a new request
+ */
+
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use super::py_agent_client::motor_data_to_json;
+use super::py_agent_config::PyAgentConfig;
+use crate::py_error::{PyAgentSdkError, PyFeagiError};
+use feagi_agent::SdkError;
+use feagi_data_structures::FeagiDataError;
+use feagi_data_structures::neuron_voxels::xyzp::CorticalMappedXYZPNeuronVoxels;
+use feagi_serialization::FeagiByteContainer;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// Inproc endpoint (private to the client's ZMQ context) used to wake the I/O thread out of its poll
+const WAKE_ENDPOINT: &str = "inproc://feagi-agent-io-wake";
+
+/// Sets the result of an asyncio future, unless it was already cancelled. Runs on the event loop
+#[pyfunction]
+fn set_future_result(future: &Bound<'_, PyAny>, value: &Bound<'_, PyAny>) -> PyResult<()> {
+    if !future.call_method0("done")?.is_truthy()? {
+        future.call_method1("set_result", (value,))?;
+    }
+    Ok(())
+}
+
+/// Sets the exception of an asyncio future, unless it was already cancelled. Runs on the event loop
+#[pyfunction]
+fn set_future_exception(future: &Bound<'_, PyAny>, exception: &Bound<'_, PyAny>) -> PyResult<()> {
+    if !future.call_method0("done")?.is_truthy()? {
+        future.call_method1("set_exception", (exception,))?;
+    }
+    Ok(())
+}
+
+/// An asyncio future awaited from Python, completed from the I/O thread
+struct PendingFuture {
+    event_loop: Py<PyAny>,
+    future: Py<PyAny>,
+}
+
+impl PendingFuture {
+    /// Creates a future on the running event loop. Fails if called outside a coroutine
+    fn new_on_running_loop(py: Python<'_>) -> PyResult<(Self, Bound<'_, PyAny>)> {
+        let event_loop = py.import("asyncio")?.call_method0("get_running_loop")?;
+        let future = event_loop.call_method0("create_future")?;
+        let pending = PendingFuture {
+            event_loop: event_loop.unbind(),
+            future: future.clone().unbind(),
+        };
+        Ok((pending, future))
+    }
+
+    /// Whether the future was already completed (in practice, cancelled by the awaiting side)
+    fn is_done(&self) -> bool {
+        Python::attach(|py| {
+            self.future.bind(py).call_method0("done")
+                .and_then(|done| done.is_truthy())
+                .unwrap_or(true)
+        })
+    }
+
+    fn resolve(self, make_value: impl FnOnce(Python<'_>) -> PyResult<Bound<'_, PyAny>>) {
+        Python::attach(|py| {
+            let scheduled = make_value(py).and_then(|value| {
+                let setter = wrap_pyfunction!(set_future_result, py)?;
+                self.event_loop.bind(py).call_method1("call_soon_threadsafe", (setter, self.future.bind(py), value))
+            });
+            if let Err(e) = scheduled {
+                // Typically the event loop was closed while the future was pending
+                tracing::warn!("[ASYNC-CLIENT] Unable to complete awaited future: {}", e);
+            }
+        });
+    }
+
+    fn reject(self, error: PyErr) {
+        Python::attach(|py| {
+            let exception = error.into_value(py);
+            let scheduled = wrap_pyfunction!(set_future_exception, py).and_then(|setter| {
+                self.event_loop.bind(py).call_method1("call_soon_threadsafe", (setter, self.future.bind(py), exception))
+            });
+            if let Err(e) = scheduled {
+                tracing::warn!("[ASYNC-CLIENT] Unable to complete awaited future: {}", e);
+            }
+        });
+    }
+
+    fn resolve_none(self) {
+        self.resolve(|py| Ok(py.None().into_bound(py)));
+    }
+
+    fn complete(self, result: Result<(), PyErr>) {
+        match result {
+            Ok(()) => self.resolve_none(),
+            Err(e) => self.reject(e),
+        }
+    }
+}
+
+/// Requests sent from Python to the I/O thread
+enum IoRequest {
+    Connect(PendingFuture),
+    SendSensoryBytes(Vec<u8>, PendingFuture),
+    ReceiveMotor(Option<Instant>, PendingFuture),
+    Shutdown,
+}
+
+/// Milliseconds until the given instant for zmq::poll, rounded up. -1 (wait indefinitely) if None
+fn poll_timeout_ms(deadline: Option<Instant>, now: Instant) -> i64 {
+    match deadline {
+        Some(deadline) => deadline.saturating_duration_since(now).as_micros().div_ceil(1000) as i64,
+        None => -1,
+    }
+}
+
+/// Decodes a motor payload (FeagiByteContainer bytes). None if the container holds no structure
+fn decode_motor_payload(payload: &[u8]) -> Result<Option<CorticalMappedXYZPNeuronVoxels>, FeagiDataError> {
+    let mut byte_container = FeagiByteContainer::new_empty();
+    byte_container.try_write_data_by_copy_and_verify(payload)?;
+    if byte_container.try_get_number_contained_structures()? == 0 {
+        return Ok(None);
+    }
+
+    let structure = byte_container.try_create_new_struct_from_index(0)?;
+    structure.as_any()
+        .downcast_ref::<CorticalMappedXYZPNeuronVoxels>()
+        .cloned()
+        .map(Some)
+        .ok_or_else(|| FeagiDataError::DeserializationError("Motor data is not CorticalMappedXYZPNeuronVoxels!".into()))
+}
+
+/// State owned by the I/O thread
+struct IoWorker {
+    client: feagi_agent::AgentClient,
+    context: zmq::Context,
+    /// Endpoint to subscribe to motor data from once connected, if the agent receives motor data
+    motor_endpoint: Option<String>,
+    /// Own subscription to motor data, as the client's socket cannot be polled. The client's subscription
+    /// is never read, so at most ZMQ's high water mark of motor messages queue up on it
+    motor_socket: Option<zmq::Socket>,
+    /// Bound to WAKE_ENDPOINT, receives an empty message for every request sent
+    wake: zmq::Socket,
+    registered: Arc<AtomicBool>,
+    motor_waiters: VecDeque<(Option<Instant>, PendingFuture)>,
+    /// Motor payloads received after their waiter was cancelled, delivered to the next waiter in order
+    undelivered_motor_data: VecDeque<String>,
+}
+
+impl IoWorker {
+    fn run(mut self, requests: Receiver<IoRequest>) {
+        'io: loop {
+            loop {
+                match requests.try_recv() {
+                    Ok(IoRequest::Shutdown) | Err(TryRecvError::Disconnected) => break 'io,
+                    Ok(request) => self.handle_request(request),
+                    Err(TryRecvError::Empty) => break,
+                }
+            }
+
+            self.poll_motor_data();
+            self.expire_motor_waiters();
+
+            if let Err(e) = self.wait() {
+                tracing::error!("[ASYNC-CLIENT] Polling failed, stopping I/O thread: {}", e);
+                break;
+            }
+        }
+
+        let closed = || PyErr::from(PyAgentSdkError::from(SdkError::Other("Async agent client was closed".to_string())));
+        for (_, waiter) in self.motor_waiters.drain(..) {
+            waiter.reject(closed());
+        }
+        for request in requests.try_iter() {
+            match request {
+                IoRequest::Connect(waiter)
+                | IoRequest::SendSensoryBytes(_, waiter)
+                | IoRequest::ReceiveMotor(_, waiter) => waiter.reject(closed()),
+                IoRequest::Shutdown => {}
+            }
+        }
+    }
+
+    /// Sleeps until a request is sent, motor data arrives while awaited, or the nearest receive times out
+    fn wait(&self) -> Result<(), zmq::Error> {
+        let nearest_deadline = self.motor_waiters.iter().filter_map(|(deadline, _)| *deadline).min();
+        let mut poll_items = vec![self.wake.as_poll_item(zmq::POLLIN)];
+        if let Some(motor_socket) = self.motor_socket.as_ref().filter(|_| !self.motor_waiters.is_empty()) {
+            poll_items.push(motor_socket.as_poll_item(zmq::POLLIN));
+        }
+        zmq::poll(&mut poll_items, poll_timeout_ms(nearest_deadline, Instant::now()))?;
+        while self.wake.recv_bytes(zmq::DONTWAIT).is_ok() {}
+        Ok(())
+    }
+
+    fn connect(&mut self) -> Result<(), SdkError> {
+        self.client.connect()?;
+        if let Some(motor_endpoint) = &self.motor_endpoint {
+            let motor_socket = self.context.socket(zmq::SUB)?;
+            motor_socket.set_linger(0)?;
+            motor_socket.connect(motor_endpoint)?;
+            // FEAGI publishes either [topic, data] or [data], so every topic is accepted
+            motor_socket.set_subscribe(b"")?;
+            self.motor_socket = Some(motor_socket);
+        }
+        Ok(())
+    }
+
+    fn handle_request(&mut self, request: IoRequest) {
+        match request {
+            IoRequest::Connect(waiter) => {
+                let result = self.connect().map_err(|e| PyAgentSdkError::from(e).into());
+                self.registered.store(self.client.is_registered(), Ordering::Release);
+                waiter.complete(result);
+            }
+            IoRequest::SendSensoryBytes(bytes, waiter) => {
+                waiter.complete(self.client.send_sensory_bytes(bytes).map_err(|e| PyAgentSdkError::from(e).into()));
+            }
+            IoRequest::ReceiveMotor(deadline, waiter) => {
+                self.motor_waiters.push_back((deadline, waiter));
+            }
+            IoRequest::Shutdown => {}
+        }
+    }
+
+    /// Hands out buffered and newly received motor data to the waiters, oldest first
+    fn poll_motor_data(&mut self) {
+        while !self.motor_waiters.is_empty() {
+            let motor_data = match self.undelivered_motor_data.pop_front() {
+                Some(motor_data) => motor_data,
+                None => match self.receive_motor_data() {
+                    Ok(Some(motor_data)) => motor_data,
+                    Ok(None) => return,
+                    Err(e) => {
+                        let error = PyErr::from(PyAgentSdkError::from(e));
+                        for (_, waiter) in self.motor_waiters.drain(..) {
+                            waiter.reject(Python::attach(|py| error.clone_ref(py)));
+                        }
+                        return;
+                    }
+                },
+            };
+
+            match self.pop_active_motor_waiter() {
+                Some(waiter) => waiter.resolve(|py| Ok(pyo3::types::PyString::new(py, &motor_data).into_any())),
+                None => self.undelivered_motor_data.push_back(motor_data),
+            }
+        }
+    }
+
+    /// Receives the next motor data without blocking, as JSON
+    fn receive_motor_data(&self) -> Result<Option<String>, SdkError> {
+        if !self.client.is_registered() {
+            return Err(SdkError::NotRegistered);
+        }
+        let motor_socket = self.motor_socket.as_ref()
+            .ok_or_else(|| SdkError::Other("Motor socket not initialized (not a motor agent?)".to_string()))?;
+
+        loop {
+            let frames = match motor_socket.recv_multipart(zmq::DONTWAIT) {
+                Ok(frames) => frames,
+                Err(zmq::Error::EAGAIN) => return Ok(None),
+                Err(e) => return Err(e.into()),
+            };
+            let Some(payload) = frames.last() else { continue };
+            let motor_data = decode_motor_payload(payload)
+                .map_err(|e| SdkError::Other(format!("Failed to decode motor data: {}", e)))?;
+            if let Some(motor_data) = motor_data {
+                return Ok(Some(motor_data_to_json(&motor_data)));
+            }
+        }
+    }
+
+    /// Pops the oldest waiter still being awaited, dropping cancelled ones
+    fn pop_active_motor_waiter(&mut self) -> Option<PendingFuture> {
+        while let Some((_, waiter)) = self.motor_waiters.pop_front() {
+            if !waiter.is_done() {
+                return Some(waiter);
+            }
+        }
+        None
+    }
+
+    /// Resolves waiters whose timeout elapsed without motor data with None
+    fn expire_motor_waiters(&mut self) {
+        let now = Instant::now();
+        let (expired, waiting): (VecDeque<_>, VecDeque<_>) = self.motor_waiters.drain(..)
+            .partition(|(deadline, _)| deadline.is_some_and(|deadline| deadline <= now));
+        self.motor_waiters = waiting;
+        for (_, waiter) in expired {
+            waiter.resolve_none();
+        }
+    }
+}
+
+/// Agent client for asyncio applications. Every method returns an awaitable and never blocks the event loop
+#[pyclass(name = "PyAsyncAgentClient")]
+pub struct PyAsyncAgentClient {
+    requests: Sender<IoRequest>,
+    /// Connected to the wake socket of the I/O thread
+    waker: Mutex<zmq::Socket>,
+    registered: Arc<AtomicBool>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl PyAsyncAgentClient {
+    fn submit(&self, request: IoRequest) -> PyResult<()> {
+        self.requests.send(request).map_err(|_| {
+            PyAgentSdkError::from(SdkError::Other("Async agent client is closed".to_string()))
+        })?;
+        self.wake();
+        Ok(())
+    }
+
+    /// Wakes the I/O thread to handle the requests sent so far
+    fn wake(&self) {
+        let waker = self.waker.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        // A full queue already guarantees a wake up
+        let _ = waker.send(&[][..], zmq::DONTWAIT);
+    }
+
+    fn shutdown(&self) {
+        let _ = self.requests.send(IoRequest::Shutdown);
+        self.wake();
+    }
+}
+
+#[pymethods]
+impl PyAsyncAgentClient {
+    #[new]
+    fn new(config: &PyAgentConfig) -> PyResult<Self> {
+        let client = feagi_agent::AgentClient::new(config.inner().clone())
+            .map_err(PyAgentSdkError::from)?;
+
+        let zmq_error = |e: zmq::Error| PyAgentSdkError::from(SdkError::from(e));
+        let context = zmq::Context::new();
+        let wake = context.socket(zmq::PAIR).map_err(zmq_error)?;
+        wake.bind(WAKE_ENDPOINT).map_err(zmq_error)?;
+        let waker = context.socket(zmq::PAIR).map_err(zmq_error)?;
+        // Lingering lets the shutdown wake sent right before the client is dropped still reach the I/O thread
+        waker.set_linger(100).map_err(zmq_error)?;
+        waker.connect(WAKE_ENDPOINT).map_err(zmq_error)?;
+
+        let receives_motor_data = matches!(config.inner().agent_type, feagi_agent::AgentType::Motor | feagi_agent::AgentType::Both);
+        let registered = Arc::new(AtomicBool::new(false));
+        let worker = IoWorker {
+            client,
+            motor_endpoint: receives_motor_data.then(|| config.inner().motor_endpoint.clone()),
+            motor_socket: None,
+            context,
+            wake,
+            registered: registered.clone(),
+            motor_waiters: VecDeque::new(),
+            undelivered_motor_data: VecDeque::new(),
+        };
+        let (requests, receiver) = mpsc::channel();
+        let worker = std::thread::Builder::new()
+            .name("feagi-agent-io".to_string())
+            .spawn(move || worker.run(receiver))
+            .map_err(|e| PyAgentSdkError::from(SdkError::ThreadError(
+                format!("Failed to spawn I/O thread: {}", e)
+            )))?;
+
+        Ok(PyAsyncAgentClient {
+            requests,
+            waker: Mutex::new(waker),
+            registered,
+            worker: Some(worker),
+        })
+    }
+
+    /// Connect and register with FEAGI. Must be awaited from a running event loop
+    fn connect<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let (pending, future) = PendingFuture::new_on_running_loop(py)?;
+        self.submit(IoRequest::Connect(pending))?;
+        Ok(future)
+    }
+
+    /// Send pre-serialized sensory bytes to FEAGI (FeagiByteContainer bytes).
+    /// Must be awaited from a running event loop
+    fn send_sensory_bytes<'py>(&self, py: Python<'py>, payload: &Bound<'py, PyBytes>) -> PyResult<Bound<'py, PyAny>> {
+        let (pending, future) = PendingFuture::new_on_running_loop(py)?;
+        self.submit(IoRequest::SendSensoryBytes(payload.as_bytes().to_vec(), pending))?;
+        Ok(future)
+    }
+
+    /// Wait for the next motor data, as JSON in the same format as PyAgentClient.receive_motor_data.
+    /// Resolves to None if no data arrived within the timeout (in seconds). Waits forever if timeout is None
+    #[pyo3(signature = (timeout=None))]
+    fn receive_motor<'py>(&self, py: Python<'py>, timeout: Option<f64>) -> PyResult<Bound<'py, PyAny>> {
+        let deadline = match timeout {
+            None => None,
+            Some(timeout) if timeout.is_finite() && timeout >= 0.0 => Some(Instant::now() + Duration::from_secs_f64(timeout)),
+            Some(timeout) => return Err(PyFeagiError::from(FeagiDataError::BadParameters(
+                format!("Timeout must be a non-negative number of seconds, got {}", timeout)
+            )).into()),
+        };
+        let (pending, future) = PendingFuture::new_on_running_loop(py)?;
+        self.submit(IoRequest::ReceiveMotor(deadline, pending))?;
+        Ok(future)
+    }
+
+    /// Check if agent is registered
+    fn is_registered(&self) -> bool {
+        self.registered.load(Ordering::Acquire)
+    }
+
+    /// Stop the I/O thread. Pending receives fail with AgentError. The agent deregisters once dropped
+    fn close(&mut self, py: Python<'_>) -> PyResult<()> {
+        self.shutdown();
+        if let Some(worker) = self.worker.take() {
+            // The worker needs the GIL to complete futures, so it must be released while joining
+            py.detach(|| worker.join())
+                .map_err(|_| PyAgentSdkError::from(SdkError::ThreadError("I/O thread panicked".to_string())))?;
+        }
+        Ok(())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("PyAsyncAgentClient(registered={})", self.is_registered())
+    }
+}
+
+impl Drop for PyAsyncAgentClient {
+    fn drop(&mut self) {
+        // Signal the worker to exit but do not join, as the GIL may be held here
+        self.shutdown();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::feagi_agent_sdk::py_mock_feagi_server::testing::run_against_mock_server;
+
+    #[test]
+    fn async_client_round_trips_sensory_and_motor_data() {
+        run_against_mock_server(cr#"
+import asyncio, json
+
+async def round_trip():
+    client = agent.PyAsyncAgentClient(agent_config('async'))
+    await client.connect()
+    assert client.is_registered()
+    assert server.wait_for_registration('async')
+
+    deadline = time.monotonic() + 5.0
+    await client.send_sensory_bytes(b'sensory')
+    while not server.wait_for_sensory(1, 0.05):
+        assert time.monotonic() < deadline, 'No sensory data received'
+        await client.send_sensory_bytes(b'sensory')
+    assert server.get_sensory_payloads()[0] == b'sensory'
+
+    assert await client.receive_motor(timeout=0.05) is None
+    payload = motor_payload()
+    deadline = time.monotonic() + 5.0
+    motor_data = None
+    while motor_data is None and time.monotonic() < deadline:
+        server.publish_motor(payload)
+        motor_data = await client.receive_motor(timeout=0.05)
+    assert json.loads(motor_data) == {'___power': {'x': [0], 'y': [0], 'z': [0], 'p': [1.0]}}
+    client.close()
+
+asyncio.run(round_trip())
+"#);
+    }
+}
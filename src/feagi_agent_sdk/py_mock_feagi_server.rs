@@ -0,0 +1,814 @@
+/*
+ * Local mock of the FEAGI agent endpoints, for testing agents without a running FEAGI
+ *
+ * A background Rust thread owns the ZMQ sockets and answers the registration channel (register,
+ * heartbeat, deregister) and the control channel (burst engine, agent list, genome metadata) as FEAGI
+ * does, collects sensory pushes and publishes scripted motor and visualization payloads. The thread
+ * sleeps in zmq::poll until a message arrives, a reply or payload is due, or Python wakes it up
+ */
+
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use crate::py_error::PyAgentSdkError;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// Inproc endpoint (private to the server's ZMQ context) used to wake the server thread out of its poll
+const WAKE_ENDPOINT: &str = "inproc://feagi-mock-server-wake";
+
+/// Default time a stopping server keeps answering agents that are still registered, so they can deregister
+const DEFAULT_STOP_GRACE_PERIOD: f64 = 5.0;
+
+/// Longest the wait_for_* methods wait on the server thread before checking for Python signals (Ctrl+C)
+const SIGNAL_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Channels answering requests
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RequestChannel {
+    Registration,
+    Control,
+}
+
+/// A request received on the registration or control channel
+#[derive(Debug, Clone)]
+struct MockRequest {
+    method: String,
+    path: String,
+    body: serde_json::Value,
+}
+
+/// A reply to send once due
+struct PendingReply {
+    due: Instant,
+    channel: RequestChannel,
+    envelope: Vec<Vec<u8>>,
+    response: serde_json::Value,
+}
+
+/// A motor or visualization payload to publish once due
+struct ScheduledPayload {
+    due: Instant,
+    topic: Option<Vec<u8>>,
+    payload: Vec<u8>,
+}
+
+/// State shared between the Python object and the server thread
+struct MockState {
+    /// Registration request body of every registered agent
+    registered_agents: BTreeMap<String, serde_json::Value>,
+    heartbeats: HashMap<String, usize>,
+    requests: Vec<MockRequest>,
+    sensory_payloads: Vec<Vec<u8>>,
+    scheduled_motor_payloads: Vec<ScheduledPayload>,
+    motor_payloads_published: usize,
+    scheduled_visualization_payloads: Vec<ScheduledPayload>,
+    visualization_payloads_published: usize,
+    registration_error: Option<String>,
+    response_delay: Duration,
+    sensory_paused: bool,
+    simulation_timestep: f64,
+    burst_engine_paused: bool,
+    genome_metadata: serde_json::Value,
+    /// Scripted (status, body) replies of the control channel by (method, route), taking precedence
+    control_responses: HashMap<(String, String), (u16, serde_json::Value)>,
+    /// Set once stopping. The server thread exits when no agent is registered anymore, or at this deadline
+    stop_deadline: Option<Instant>,
+}
+
+/// MockState with a condition variable notified whenever the server thread records a request (including
+/// registrations and heartbeats) or sensory payload
+#[derive(Default)]
+struct SharedState {
+    state: Mutex<MockState>,
+    recorded: Condvar,
+}
+
+impl SharedState {
+    fn lock(&self) -> MutexGuard<'_, MockState> {
+        // A panic while holding the lock leaves the state consistent enough for a test double
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for MockState {
+    fn default() -> Self {
+        MockState {
+            registered_agents: BTreeMap::new(),
+            heartbeats: HashMap::new(),
+            requests: Vec::new(),
+            sensory_payloads: Vec::new(),
+            scheduled_motor_payloads: Vec::new(),
+            motor_payloads_published: 0,
+            scheduled_visualization_payloads: Vec::new(),
+            visualization_payloads_published: 0,
+            registration_error: None,
+            response_delay: Duration::ZERO,
+            sensory_paused: false,
+            simulation_timestep: 0.1,
+            burst_engine_paused: false,
+            genome_metadata: serde_json::json!({"genome_id": "mock_genome", "genome_title": "Mock Genome", "version": "2.0"}),
+            control_responses: HashMap::new(),
+            stop_deadline: None,
+        }
+    }
+}
+
+impl MockState {
+    /// Builds the response FEAGI would give to a registration channel request, updating the registry
+    fn respond(&mut self, request: &MockRequest) -> serde_json::Value {
+        let agent_id = request.body.get("agent_id").and_then(|id| id.as_str()).unwrap_or_default().to_string();
+        match (request.method.as_str(), request.path.as_str()) {
+            ("POST", "/v1/agent/register") => {
+                if let Some(error) = &self.registration_error {
+                    serde_json::json!({"status": 400, "body": {"error": error}})
+                } else if self.registered_agents.contains_key(&agent_id) {
+                    serde_json::json!({"status": 409, "body": {"error": format!("Agent {} already registered", agent_id)}})
+                } else {
+                    self.registered_agents.insert(agent_id.clone(), request.body.clone());
+                    serde_json::json!({"status": 200, "body": {"agent_id": agent_id, "message": "Agent registered"}})
+                }
+            }
+            ("POST", "/v1/agent/heartbeat") => {
+                if self.registered_agents.contains_key(&agent_id) {
+                    *self.heartbeats.entry(agent_id).or_default() += 1;
+                    serde_json::json!({"status": 200, "body": {"message": "ok"}})
+                } else {
+                    serde_json::json!({"status": 404, "body": {"error": format!("Agent {} not found in registry", agent_id)}})
+                }
+            }
+            ("DELETE", "/v1/agent/deregister") => {
+                self.registered_agents.remove(&agent_id);
+                serde_json::json!({"status": "success", "message": format!("Agent {} deregistered", agent_id)})
+            }
+            _ => serde_json::json!({"status": 404, "body": {"error": format!("Unknown path {} {}", request.method, request.path)}}),
+        }
+    }
+
+    /// Builds the response FEAGI would give to a control channel (REST API over ZMQ) request
+    fn respond_control(&mut self, request: &MockRequest) -> serde_json::Value {
+        if let Some((status, body)) = self.control_responses.get(&(request.method.clone(), request.path.clone())) {
+            return serde_json::json!({"status": status, "body": body});
+        }
+        match (request.method.as_str(), request.path.as_str()) {
+            ("GET", "/v1/burst_engine/simulation_timestep") => {
+                serde_json::json!({"status": 200, "body": {"simulation_timestep": self.simulation_timestep}})
+            }
+            ("POST", "/v1/burst_engine/simulation_timestep") => {
+                match request.body.get("simulation_timestep").and_then(|timestep| timestep.as_f64()) {
+                    Some(timestep) if timestep > 0.0 => {
+                        self.simulation_timestep = timestep;
+                        serde_json::json!({"status": 200, "body": {"simulation_timestep": timestep}})
+                    }
+                    _ => serde_json::json!({"status": 422, "body": {"error": "simulation_timestep must be a positive number"}}),
+                }
+            }
+            ("POST", "/v1/burst_engine/control") => {
+                match request.body.get("action").and_then(|action| action.as_str()) {
+                    Some("pause") => self.burst_engine_paused = true,
+                    Some("resume") => self.burst_engine_paused = false,
+                    _ => return serde_json::json!({"status": 422, "body": {"error": "action must be pause or resume"}}),
+                }
+                serde_json::json!({"status": 200, "body": {"paused": self.burst_engine_paused}})
+            }
+            ("GET", "/v1/agent/list") => {
+                serde_json::json!({"status": 200, "body": self.registered_agents.keys().collect::<Vec<_>>()})
+            }
+            ("GET", "/v1/genome/metadata") => serde_json::json!({"status": 200, "body": self.genome_metadata}),
+            _ => serde_json::json!({"status": 404, "body": {"error": format!("Unknown route {} {}", request.method, request.path)}}),
+        }
+    }
+}
+
+/// The sockets bound by the server, moved into the server thread
+struct MockSockets {
+    registration: zmq::Socket,
+    sensory: zmq::Socket,
+    motor: zmq::Socket,
+    visualization: zmq::Socket,
+    control: zmq::Socket,
+    /// Receives a message whenever Python changed something the server thread must act on
+    wake: zmq::Socket,
+}
+
+impl MockSockets {
+    fn request_socket(&self, channel: RequestChannel) -> &zmq::Socket {
+        match channel {
+            RequestChannel::Registration => &self.registration,
+            RequestChannel::Control => &self.control,
+        }
+    }
+}
+
+impl MockSockets {
+    fn bind(context: &zmq::Context, endpoints: &MockEndpoints) -> Result<(Self, MockEndpoints), zmq::Error> {
+        let bind = |socket_type: zmq::SocketType, endpoint: &str| -> Result<(zmq::Socket, String), zmq::Error> {
+            let socket = context.socket(socket_type)?;
+            socket.set_linger(0)?;
+            socket.bind(endpoint)?;
+            // Resolves wildcard ports such as tcp://127.0.0.1:*
+            let bound_endpoint = socket.get_last_endpoint()?.unwrap_or_else(|_| endpoint.to_string());
+            Ok((socket, bound_endpoint))
+        };
+        let (registration, registration_endpoint) = bind(zmq::ROUTER, &endpoints.registration)?;
+        let (sensory, sensory_endpoint) = bind(zmq::PULL, &endpoints.sensory)?;
+        let (motor, motor_endpoint) = bind(zmq::PUB, &endpoints.motor)?;
+        let (visualization, visualization_endpoint) = bind(zmq::PUB, &endpoints.visualization)?;
+        let (control, control_endpoint) = bind(zmq::ROUTER, &endpoints.control)?;
+        let (wake, _) = bind(zmq::PAIR, WAKE_ENDPOINT)?;
+        Ok((
+            MockSockets { registration, sensory, motor, visualization, control, wake },
+            MockEndpoints {
+                registration: registration_endpoint,
+                sensory: sensory_endpoint,
+                motor: motor_endpoint,
+                visualization: visualization_endpoint,
+                control: control_endpoint,
+            },
+        ))
+    }
+}
+
+#[derive(Clone)]
+struct MockEndpoints {
+    registration: String,
+    sensory: String,
+    motor: String,
+    visualization: String,
+    control: String,
+}
+
+/// Publishes the scheduled payloads that are due, keeping the others. Returns how many were published
+fn publish_due_payloads(socket: &zmq::Socket, scheduled_payloads: &mut Vec<ScheduledPayload>, now: Instant) -> usize {
+    let (due, scheduled): (Vec<ScheduledPayload>, Vec<ScheduledPayload>) = std::mem::take(scheduled_payloads)
+        .into_iter()
+        .partition(|scheduled| scheduled.due <= now);
+    *scheduled_payloads = scheduled;
+
+    let mut published = 0;
+    for scheduled in due {
+        let sent = match scheduled.topic {
+            Some(topic) => socket.send_multipart([topic, scheduled.payload], 0),
+            None => socket.send(scheduled.payload, 0),
+        };
+        match sent {
+            Ok(()) => published += 1,
+            Err(e) => tracing::warn!("[MOCK-FEAGI] Unable to publish payload: {}", e),
+        }
+    }
+    published
+}
+
+/// Receives a request, answers it from the state and queues the reply
+fn receive_request(socket: &zmq::Socket, channel: RequestChannel, shared: &SharedState, pending_replies: &mut Vec<PendingReply>) {
+    let Ok(mut frames) = socket.recv_multipart(0) else {
+        return;
+    };
+    // ROUTER frames are [identity, (request id,) (empty delimiter,) request]
+    let message = frames.pop().unwrap_or_default();
+    let request = serde_json::from_slice::<serde_json::Value>(&message)
+        .map(|request| MockRequest {
+            method: request.get("method").and_then(|m| m.as_str()).unwrap_or_default().to_uppercase(),
+            // The registration channel names the route "path", the control channel "route"
+            path: request.get("path").or_else(|| request.get("route")).and_then(|p| p.as_str()).unwrap_or_default().to_string(),
+            body: request.get("body").cloned().unwrap_or(serde_json::Value::Null),
+        });
+
+    let mut state = shared.lock();
+    let response = match request {
+        Ok(request) => {
+            let response = match channel {
+                RequestChannel::Registration => state.respond(&request),
+                RequestChannel::Control => state.respond_control(&request),
+            };
+            state.requests.push(request);
+            response
+        }
+        Err(e) => serde_json::json!({"status": 400, "body": {"error": format!("Invalid JSON: {}", e)}}),
+    };
+    pending_replies.push(PendingReply { due: Instant::now() + state.response_delay, channel, envelope: frames, response });
+    shared.recorded.notify_all();
+}
+
+/// Milliseconds until the given instant for zmq::poll, rounded up. -1 (wait indefinitely) if None
+fn poll_timeout_ms(due: Option<Instant>, now: Instant) -> i64 {
+    match due {
+        Some(due) => due.saturating_duration_since(now).as_micros().div_ceil(1000) as i64,
+        None => -1,
+    }
+}
+
+/// Body of the server thread
+fn serve(sockets: MockSockets, shared: Arc<SharedState>) {
+    let mut pending_replies: Vec<PendingReply> = Vec::new();
+    loop {
+        let now = Instant::now();
+        pending_replies.retain(|reply| {
+            if reply.due > now {
+                return true;
+            }
+            let mut frames = reply.envelope.clone();
+            frames.push(reply.response.to_string().into_bytes());
+            if let Err(e) = sockets.request_socket(reply.channel).send_multipart(frames, 0) {
+                tracing::warn!("[MOCK-FEAGI] Unable to reply: {}", e);
+            }
+            false
+        });
+
+        let (sensory_paused, next_due) = {
+            let mut state = shared.lock();
+            let state = &mut *state;
+            state.motor_payloads_published += publish_due_payloads(&sockets.motor, &mut state.scheduled_motor_payloads, now);
+            state.visualization_payloads_published += publish_due_payloads(&sockets.visualization, &mut state.scheduled_visualization_payloads, now);
+            if let Some(stop_deadline) = state.stop_deadline {
+                if state.registered_agents.is_empty() || now >= stop_deadline {
+                    break;
+                }
+            }
+            let next_due = pending_replies.iter().map(|reply| reply.due)
+                .chain(state.scheduled_motor_payloads.iter().map(|scheduled| scheduled.due))
+                .chain(state.scheduled_visualization_payloads.iter().map(|scheduled| scheduled.due))
+                .chain(state.stop_deadline)
+                .min();
+            (state.sensory_paused, next_due)
+        };
+
+        let mut poll_items = [
+            sockets.registration.as_poll_item(zmq::POLLIN),
+            sockets.sensory.as_poll_item(if sensory_paused { zmq::PollEvents::empty() } else { zmq::POLLIN }),
+            sockets.control.as_poll_item(zmq::POLLIN),
+            sockets.wake.as_poll_item(zmq::POLLIN),
+        ];
+        if let Err(e) = zmq::poll(&mut poll_items, poll_timeout_ms(next_due, now)) {
+            tracing::warn!("[MOCK-FEAGI] Poll failed: {}", e);
+            continue;
+        }
+
+        for (index, channel) in [(0, RequestChannel::Registration), (2, RequestChannel::Control)] {
+            if poll_items[index].is_readable() {
+                receive_request(sockets.request_socket(channel), channel, &shared, &mut pending_replies);
+            }
+        }
+
+        if poll_items[1].is_readable() {
+            while let Ok(payload) = sockets.sensory.recv_bytes(zmq::DONTWAIT) {
+                shared.lock().sensory_payloads.push(payload);
+            }
+            shared.recorded.notify_all();
+        }
+
+        if poll_items[3].is_readable() {
+            while sockets.wake.recv_bytes(zmq::DONTWAIT).is_ok() {}
+        }
+    }
+}
+
+/// Local stand-in for FEAGI's agent endpoints, to test agents (PyAgentClient, PyAsyncAgentClient) offline.
+/// Endpoints default to free localhost TCP ports; read the bound endpoints back after start.
+/// Clients deregister and wait for the reply when disconnected or dropped, so a stopped server keeps answering
+/// agents that are still registered for a grace period (see stop). Disconnect (or drop) clients before it ends
+#[pyclass(name = "MockFeagiServer")]
+pub struct PyMockFeagiServer {
+    endpoints: MockEndpoints,
+    shared: Arc<SharedState>,
+    thread: Option<JoinHandle<()>>,
+    /// Thread of a stopped server still answering registered agents during its grace period
+    stopping_thread: Option<JoinHandle<()>>,
+    /// Connected to the wake socket of the (last started) server thread
+    waker: Option<Mutex<zmq::Socket>>,
+}
+
+impl PyMockFeagiServer {
+    fn state(&self) -> MutexGuard<'_, MockState> {
+        self.shared.lock()
+    }
+
+    /// Wakes the server thread, so it acts on state changed from Python (scheduled payloads, pausing, stopping)
+    fn wake(&self) {
+        if let Some(waker) = &self.waker {
+            let waker = waker.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            // A full queue already guarantees a wake up
+            let _ = waker.send(&[][..], zmq::DONTWAIT);
+        }
+    }
+
+    /// Asks the server thread to exit once no agent is registered anymore, or after the grace period.
+    /// Returns the thread (if running) and whether agents are still registered
+    fn request_stop(&mut self, grace_period: f64) -> (Option<JoinHandle<()>>, bool) {
+        let agents_registered = {
+            let mut state = self.state();
+            state.stop_deadline = Some(Instant::now() + Duration::from_secs_f64(grace_period.max(0.0)));
+            !state.registered_agents.is_empty()
+        };
+        self.wake();
+        (self.thread.take(), agents_registered)
+    }
+
+    /// Waits, without holding the GIL, until the condition holds on the state or the timeout expires.
+    /// The condition is checked again whenever the server thread records traffic
+    fn wait_until(&self, py: Python<'_>, timeout: f64, condition: impl Fn(&MockState) -> bool + Sync) -> PyResult<bool> {
+        let deadline = Instant::now() + Duration::from_secs_f64(timeout.max(0.0));
+        loop {
+            let satisfied = py.detach(|| {
+                let wait = deadline.saturating_duration_since(Instant::now()).min(SIGNAL_CHECK_INTERVAL);
+                let (state, _) = self.shared.recorded
+                    .wait_timeout_while(self.state(), wait, |state| !condition(state))
+                    .unwrap_or_else(|poisoned| poisoned.into_inner());
+                condition(&state)
+            });
+            if satisfied {
+                return Ok(true);
+            }
+            if Instant::now() >= deadline {
+                return Ok(false);
+            }
+            py.check_signals()?;
+        }
+    }
+}
+
+#[pymethods]
+impl PyMockFeagiServer {
+    #[new]
+    #[pyo3(signature = (
+        registration_endpoint = "tcp://127.0.0.1:*",
+        sensory_endpoint = "tcp://127.0.0.1:*",
+        motor_endpoint = "tcp://127.0.0.1:*",
+        visualization_endpoint = "tcp://127.0.0.1:*",
+        control_endpoint = "tcp://127.0.0.1:*"
+    ))]
+    fn new(registration_endpoint: &str, sensory_endpoint: &str, motor_endpoint: &str, visualization_endpoint: &str, control_endpoint: &str) -> Self {
+        PyMockFeagiServer {
+            endpoints: MockEndpoints {
+                registration: registration_endpoint.to_string(),
+                sensory: sensory_endpoint.to_string(),
+                motor: motor_endpoint.to_string(),
+                visualization: visualization_endpoint.to_string(),
+                control: control_endpoint.to_string(),
+            },
+            shared: Arc::new(SharedState::default()),
+            thread: None,
+            stopping_thread: None,
+            waker: None,
+        }
+    }
+
+    /// Binds the sockets and starts serving. Waits for the grace period of a previous stop to end first
+    fn start(&mut self, py: Python<'_>) -> PyResult<()> {
+        if self.thread.is_some() {
+            return Err(PyAgentSdkError::from(feagi_agent::SdkError::Other("Mock FEAGI server is already running".to_string())).into());
+        }
+        if let Some(stopping_thread) = self.stopping_thread.take() {
+            py.detach(|| {
+                let _ = stopping_thread.join();
+            });
+        }
+        // The waker is the last socket of the previous context, so dropping it waits for the previous sockets to unbind
+        self.waker = None;
+        let zmq_error = |e: zmq::Error| PyAgentSdkError::from(feagi_agent::SdkError::from(e));
+        let context = zmq::Context::new();
+        let (sockets, bound_endpoints) = MockSockets::bind(&context, &self.endpoints).map_err(zmq_error)?;
+        let waker = context.socket(zmq::PAIR).map_err(zmq_error)?;
+        // Lingering lets a wake sent right before the waker is dropped still reach the server thread
+        waker.set_linger(100).map_err(zmq_error)?;
+        waker.connect(WAKE_ENDPOINT).map_err(zmq_error)?;
+        self.endpoints = bound_endpoints;
+
+        self.state().stop_deadline = None;
+        let shared = Arc::clone(&self.shared);
+        let thread = std::thread::Builder::new()
+            .name("feagi-mock-server".to_string())
+            .spawn(move || serve(sockets, shared))
+            .map_err(|e| PyAgentSdkError::from(feagi_agent::SdkError::ThreadError(e.to_string())))?;
+        self.thread = Some(thread);
+        self.waker = Some(Mutex::new(waker));
+        Ok(())
+    }
+
+    /// Stops serving. If agents are still registered, the sockets stay open in the background to answer their
+    /// heartbeats and deregistrations until every agent left or grace_period seconds passed; otherwise they are
+    /// closed before returning. Clients dropped after the grace period block on their deregistration.
+    /// Registered agents and recorded traffic are kept
+    #[pyo3(signature = (grace_period = DEFAULT_STOP_GRACE_PERIOD))]
+    fn stop(&mut self, py: Python<'_>, grace_period: f64) {
+        let (thread, agents_registered) = self.request_stop(grace_period);
+        let Some(thread) = thread else {
+            return;
+        };
+        if agents_registered {
+            self.stopping_thread = Some(thread);
+        } else {
+            py.detach(|| {
+                let _ = thread.join();
+            });
+        }
+    }
+
+    #[getter]
+    fn is_running(&self) -> bool {
+        self.thread.is_some()
+    }
+
+    #[getter]
+    fn registration_endpoint(&self) -> String {
+        self.endpoints.registration.clone()
+    }
+
+    #[getter]
+    fn sensory_endpoint(&self) -> String {
+        self.endpoints.sensory.clone()
+    }
+
+    #[getter]
+    fn motor_endpoint(&self) -> String {
+        self.endpoints.motor.clone()
+    }
+
+    #[getter]
+    fn visualization_endpoint(&self) -> String {
+        self.endpoints.visualization.clone()
+    }
+
+    #[getter]
+    fn control_endpoint(&self) -> String {
+        self.endpoints.control.clone()
+    }
+
+    //region Scripting
+
+    /// Publishes a motor payload (serialized FeagiByteContainer) after delay seconds, as a [topic, payload]
+    /// multipart message, or a single frame if no topic is given. Subscribers connecting after publication miss it
+    #[pyo3(signature = (payload, topic = None, delay = 0.0))]
+    fn publish_motor(&self, payload: &[u8], topic: Option<&str>, delay: f64) {
+        self.state().scheduled_motor_payloads.push(ScheduledPayload {
+            due: Instant::now() + Duration::from_secs_f64(delay.max(0.0)),
+            topic: topic.map(|topic| topic.as_bytes().to_vec()),
+            payload: payload.to_vec(),
+        });
+        self.wake();
+    }
+
+    /// Publishes a visualization payload (serialized FeagiByteContainer of neuron activity) after delay seconds,
+    /// as a [topic, payload] multipart message. Compressed with LZ4 as FEAGI does, unless compress is False
+    #[pyo3(signature = (payload, topic = "activity", delay = 0.0, compress = true))]
+    fn publish_visualization(&self, payload: &[u8], topic: Option<&str>, delay: f64, compress: bool) -> PyResult<()> {
+        let payload = if compress {
+            lz4::block::compress(payload, Some(lz4::block::CompressionMode::FAST(1)), true)?
+        } else {
+            payload.to_vec()
+        };
+        self.state().scheduled_visualization_payloads.push(ScheduledPayload {
+            due: Instant::now() + Duration::from_secs_f64(delay.max(0.0)),
+            topic: topic.map(|topic| topic.as_bytes().to_vec()),
+            payload,
+        });
+        self.wake();
+        Ok(())
+    }
+
+    /// Rejects every registration with the given error message, or accepts them again if None
+    #[pyo3(signature = (error_message))]
+    fn set_registration_error(&self, error_message: Option<String>) {
+        self.state().registration_error = error_message;
+    }
+
+    /// Replies to the given control request with the given status and body (JSON), instead of the built-in behavior.
+    /// A body of None removes the scripted reply
+    #[pyo3(signature = (method, route, status = 200, body_json = None))]
+    fn set_control_response(&self, method: &str, route: &str, status: u16, body_json: Option<&str>) -> PyResult<()> {
+        let key = (method.to_uppercase(), route.to_string());
+        let Some(body_json) = body_json else {
+            self.state().control_responses.remove(&key);
+            return Ok(());
+        };
+        let body = serde_json::from_str(body_json)
+            .map_err(|e| PyAgentSdkError::from(feagi_agent::SdkError::from(e)))?;
+        self.state().control_responses.insert(key, (status, body));
+        Ok(())
+    }
+
+    /// Sets the genome metadata (JSON object) returned by the control channel
+    fn set_genome_metadata(&self, metadata_json: &str) -> PyResult<()> {
+        let metadata = serde_json::from_str(metadata_json)
+            .map_err(|e| PyAgentSdkError::from(feagi_agent::SdkError::from(e)))?;
+        self.state().genome_metadata = metadata;
+        Ok(())
+    }
+
+    /// Delays every reply on the registration and control channels, to exercise client timeouts
+    fn set_response_delay(&self, seconds: f64) {
+        self.state().response_delay = Duration::from_secs_f64(seconds.max(0.0));
+    }
+
+    /// Stops (or resumes) reading sensory pushes, so they queue up and the client hits backpressure
+    fn set_sensory_paused(&self, paused: bool) {
+        self.state().sensory_paused = paused;
+        self.wake();
+    }
+
+    /// Removes an agent from the registry, as a FEAGI restart would, so its heartbeats get rejected
+    fn forget_agent(&self, agent_id: &str) -> bool {
+        let forgotten = self.state().registered_agents.remove(agent_id).is_some();
+        self.wake();
+        forgotten
+    }
+
+    //endregion
+
+    //region Inspection
+
+    #[getter]
+    fn registered_agents(&self) -> Vec<String> {
+        self.state().registered_agents.keys().cloned().collect()
+    }
+
+    /// Registration request body of the given agent as JSON, if registered
+    fn get_registration(&self, agent_id: &str) -> Option<String> {
+        self.state().registered_agents.get(agent_id).map(|body| body.to_string())
+    }
+
+    fn heartbeat_count(&self, agent_id: &str) -> usize {
+        self.state().heartbeats.get(agent_id).copied().unwrap_or(0)
+    }
+
+    /// Every request received on the registration and control channels, as (method, path, JSON body)
+    fn get_requests(&self) -> Vec<(String, String, String)> {
+        self.state().requests.iter()
+            .map(|request| (request.method.clone(), request.path.clone(), request.body.to_string()))
+            .collect()
+    }
+
+    /// Every sensory payload received, in order
+    fn get_sensory_payloads<'py>(&self, py: Python<'py>) -> Vec<Bound<'py, PyBytes>> {
+        self.state().sensory_payloads.iter().map(|payload| PyBytes::new(py, payload)).collect()
+    }
+
+    #[getter]
+    fn sensory_payload_count(&self) -> usize {
+        self.state().sensory_payloads.len()
+    }
+
+    #[getter]
+    fn motor_payloads_published(&self) -> usize {
+        self.state().motor_payloads_published
+    }
+
+    #[getter]
+    fn motor_payloads_pending(&self) -> usize {
+        self.state().scheduled_motor_payloads.len()
+    }
+
+    /// Simulation timestep (seconds per burst) as set over the control channel
+    #[getter]
+    fn simulation_timestep(&self) -> f64 {
+        self.state().simulation_timestep
+    }
+
+    #[getter]
+    fn burst_engine_paused(&self) -> bool {
+        self.state().burst_engine_paused
+    }
+
+    #[getter]
+    fn visualization_payloads_published(&self) -> usize {
+        self.state().visualization_payloads_published
+    }
+
+    /// Clears the recorded requests, heartbeats and sensory payloads
+    fn clear_received(&self) {
+        let mut state = self.state();
+        state.requests.clear();
+        state.heartbeats.clear();
+        state.sensory_payloads.clear();
+    }
+
+    /// Waits until at least count sensory payloads were received. Returns False on timeout
+    #[pyo3(signature = (count = 1, timeout = 5.0))]
+    fn wait_for_sensory(&self, py: Python<'_>, count: usize, timeout: f64) -> PyResult<bool> {
+        self.wait_until(py, timeout, |state| state.sensory_payloads.len() >= count)
+    }
+
+    /// Waits until the given agent (or any agent if None) is registered. Returns False on timeout
+    #[pyo3(signature = (agent_id = None, timeout = 5.0))]
+    fn wait_for_registration(&self, py: Python<'_>, agent_id: Option<&str>, timeout: f64) -> PyResult<bool> {
+        self.wait_until(py, timeout, |state| match agent_id {
+            Some(agent_id) => state.registered_agents.contains_key(agent_id),
+            None => !state.registered_agents.is_empty(),
+        })
+    }
+
+    //endregion
+
+    fn __enter__(mut slf: PyRefMut<'_, Self>) -> PyResult<PyRefMut<'_, Self>> {
+        let py = slf.py();
+        slf.start(py)?;
+        Ok(slf)
+    }
+
+    fn __exit__(&mut self, py: Python<'_>, _exc_type: Py<PyAny>, _exc_value: Py<PyAny>, _traceback: Py<PyAny>) -> bool {
+        self.stop(py, DEFAULT_STOP_GRACE_PERIOD);
+        false
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "MockFeagiServer(registration={}, sensory={}, motor={}, visualization={}, control={}, running={})",
+            self.endpoints.registration, self.endpoints.sensory, self.endpoints.motor, self.endpoints.visualization,
+            self.endpoints.control, self.thread.is_some()
+        )
+    }
+}
+
+impl Drop for PyMockFeagiServer {
+    fn drop(&mut self) {
+        // Not joined, as the GIL may be held here while a client still needs to deregister.
+        // The thread exits on its own once the agents left or the grace period ended
+        self.request_stop(DEFAULT_STOP_GRACE_PERIOD);
+    }
+}
+
+/// Runs Python test scripts against a MockFeagiServer, through the Python API of the module
+#[cfg(test)]
+pub(crate) mod testing {
+    use pyo3::prelude::*;
+    use pyo3::types::PyDict;
+    use std::ffi::CStr;
+    use std::sync::Once;
+
+    /// Defines `feagi` (the module), `agent`, `cortical_area`, a started MockFeagiServer as `server`, and helpers
+    /// retrying the first sensory and motor payloads until the connection is established
+    const PRELUDE: &CStr = cr#"
+import time
+import feagi_rust_py_libs as feagi
+agent = feagi.feagi_agent
+cortical_area = feagi.data_structures.genomic.cortical_area
+
+server = agent.MockFeagiServer()
+server.start()
+
+def agent_config(agent_id, agent_type='both'):
+    config = agent.PyAgentConfig(agent_id, agent.AgentType(agent_type))
+    config.with_registration_endpoint(server.registration_endpoint)
+    config.with_sensory_endpoint(server.sensory_endpoint)
+    config.with_motor_endpoint(server.motor_endpoint)
+    config.with_control_endpoint(server.control_endpoint)
+    config.with_motor_capability('motor', 1, ['omot00'])
+    config.with_vision_capability('camera', 4, 4, 1, 'isvi00')
+    return config
+
+def motor_payload(cortical_id=cortical_area.CorticalID.try_from_bytes(b'___power'), z=0, p=1.0):
+    xyzp = feagi.data_structures.neurons_voxels.xyzp
+    arrays = xyzp.PyNeuronVoxelXYZPArrays()
+    arrays.push(xyzp.PyNeuronVoxelXYZP(0, 0, z, p))
+    voxels = xyzp.CorticalMappedXYZPNeuronVoxels()
+    voxels.insert(cortical_id, arrays)
+    container = feagi.data_serialization.FeagiByteContainer()
+    container.add_struct(voxels, None)
+    return container.copy_out_as_byte_vector()
+
+def send_sensory_until_received(send, timeout=5.0):
+    # Pushes are dropped until the connection is established, so send until one arrives
+    count = server.sensory_payload_count + 1
+    deadline = time.monotonic() + timeout
+    while time.monotonic() < deadline:
+        send()
+        if server.wait_for_sensory(count, 0.05):
+            return
+    raise AssertionError('No sensory data received')
+
+def publish_motor_until(payload, receive, timeout=5.0):
+    # Subscribers miss payloads published before their connection is established, so publish until one arrives
+    deadline = time.monotonic() + timeout
+    while time.monotonic() < deadline:
+        server.publish_motor(payload)
+        received = receive()
+        if received:
+            return received
+        time.sleep(0.05)
+    raise AssertionError('No motor data received')
+"#;
+
+    /// Runs the script after PRELUDE, then stops the server. Panics with the Python error if either raises
+    pub(crate) fn run_against_mock_server(script: &CStr) {
+        static INITIALIZE: Once = Once::new();
+        INITIALIZE.call_once(|| {
+            use crate::feagi_rust_py_libs;
+            pyo3::append_to_inittab!(feagi_rust_py_libs);
+            Python::initialize();
+        });
+
+        Python::attach(|py| {
+            let globals = PyDict::new(py);
+            let result = py.run(PRELUDE, Some(&globals), None)
+                .and_then(|_| py.run(script, Some(&globals), None));
+            if let Ok(Some(server)) = globals.get_item("server") {
+                server.call_method0("stop").expect("Unable to stop the mock server");
+            }
+            if let Err(e) = result {
+                let traceback = e.traceback(py).and_then(|traceback| traceback.format().ok()).unwrap_or_default();
+                panic!("{}{}", traceback, e);
+            }
+        });
+    }
+}
@@ -0,0 +1,200 @@
+/*
+ * Subscriber to FEAGI's visualization stream (neuron activity snapshots)
+ */
+
+use pyo3::prelude::*;
+use pyo3::exceptions::PyStopIteration;
+use feagi_data_structures::FeagiDataError;
+use feagi_data_structures::genomic::cortical_area::CorticalID;
+use feagi_data_structures::neuron_voxels::xyzp::CorticalMappedXYZPNeuronVoxels;
+use feagi_serialization::FeagiByteContainer;
+use crate::feagi_data_structures::genomic::cortical_area::PyCorticalID;
+use crate::feagi_data_structures::neurons_voxels::xyzp::PyCorticalMappedXYZPNeuronVoxels;
+use crate::py_error::{PyAgentSdkError, PyFeagiError};
+use std::collections::HashSet;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Longest time spent waiting for a snapshot without checking for Python signals (such as Ctrl+C)
+const SIGNAL_CHECK_INTERVAL_MS: i64 = 50;
+
+/// Decodes a visualization payload into neuron activity. FEAGI LZ4 compresses the serialized
+/// FeagiByteContainer; uncompressed containers are accepted as well
+fn decode_visualization_payload(payload: &[u8]) -> Result<CorticalMappedXYZPNeuronVoxels, FeagiDataError> {
+    let mut byte_container = FeagiByteContainer::new_empty();
+    if byte_container.try_write_data_by_copy_and_verify(payload).is_err() {
+        let decompressed = lz4::block::decompress(payload, None)
+            .map_err(|e| FeagiDataError::DeserializationError(format!("Unable to decompress visualization payload: {}", e)))?;
+        byte_container.try_write_data_by_copy_and_verify(&decompressed)?;
+    }
+
+    let structure = byte_container.try_create_new_struct_from_index(0)?;
+    structure.as_any()
+        .downcast_ref::<CorticalMappedXYZPNeuronVoxels>()
+        .cloned()
+        .ok_or_else(|| FeagiDataError::DeserializationError("Visualization payload does not contain neuron voxels!".into()))
+}
+
+/// Subscribes to FEAGI's visualization stream and yields neuron activity snapshots as
+/// CorticalMappedXYZPNeuronVoxels, optionally only with the given cortical areas.
+/// Does not require registration; create one directly or with PyAgentClient.subscribe_visualization
+#[pyclass(name = "VisualizationSubscriber")]
+pub struct PyVisualizationSubscriber {
+    endpoint: String,
+    socket: Option<Mutex<zmq::Socket>>,
+    cortical_filter: Option<HashSet<CorticalID>>,
+    snapshots_received: usize,
+}
+
+impl PyVisualizationSubscriber {
+    pub(crate) fn connect(endpoint: &str, cortical_ids: Option<Vec<PyCorticalID>>) -> PyResult<Self> {
+        if endpoint.is_empty() {
+            return Err(PyAgentSdkError::from(feagi_agent::SdkError::InvalidConfig("Visualization endpoint is not set".to_string())).into());
+        }
+        let socket = (|| -> Result<zmq::Socket, zmq::Error> {
+            let socket = zmq::Context::new().socket(zmq::SUB)?;
+            socket.set_linger(0)?;
+            socket.connect(endpoint)?;
+            socket.set_subscribe(b"")?;
+            Ok(socket)
+        })().map_err(|e| PyAgentSdkError::from(feagi_agent::SdkError::from(e)))?;
+
+        Ok(PyVisualizationSubscriber {
+            endpoint: endpoint.to_string(),
+            socket: Some(Mutex::new(socket)),
+            cortical_filter: cortical_ids.map(|ids| ids.into_iter().map(|id| id.inner).collect()),
+            snapshots_received: 0,
+        })
+    }
+
+    fn socket(&self) -> PyResult<&Mutex<zmq::Socket>> {
+        self.socket.as_ref().ok_or_else(|| PyAgentSdkError::from(feagi_agent::SdkError::Other("Visualization subscriber is closed".to_string())).into())
+    }
+
+    /// Receives the last frame of the next message (the payload, after the topic frame if any), waiting up to timeout
+    /// (forever if None) without holding the GIL
+    fn receive_payload(&self, py: Python<'_>, timeout: Option<f64>) -> PyResult<Option<Vec<u8>>> {
+        let socket = self.socket()?;
+        let deadline = timeout.map(|timeout| Instant::now() + Duration::from_secs_f64(timeout.max(0.0)));
+        loop {
+            let wait_ms = match deadline {
+                Some(deadline) => (deadline.saturating_duration_since(Instant::now()).as_millis() as i64).min(SIGNAL_CHECK_INTERVAL_MS),
+                None => SIGNAL_CHECK_INTERVAL_MS,
+            };
+            let received = py.detach(|| -> Result<Option<Vec<u8>>, zmq::Error> {
+                let socket = socket.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+                if socket.poll(zmq::POLLIN, wait_ms)? == 0 {
+                    return Ok(None);
+                }
+                Ok(socket.recv_multipart(0)?.pop())
+            }).map_err(|e| PyAgentSdkError::from(feagi_agent::SdkError::from(e)))?;
+
+            if received.is_some() {
+                return Ok(received);
+            }
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                return Ok(None);
+            }
+            py.check_signals()?;
+        }
+    }
+
+    fn decode_snapshot(&mut self, py: Python<'_>, payload: &[u8]) -> PyResult<Py<PyCorticalMappedXYZPNeuronVoxels>> {
+        let mut snapshot = decode_visualization_payload(payload).map_err(PyFeagiError::from)?;
+        if let Some(cortical_filter) = &self.cortical_filter {
+            snapshot.mappings.retain(|cortical_id, _| cortical_filter.contains(cortical_id));
+        }
+        self.snapshots_received += 1;
+        PyCorticalMappedXYZPNeuronVoxels::python_etc_child_constructor(py, snapshot)
+    }
+}
+
+#[pymethods]
+impl PyVisualizationSubscriber {
+    #[new]
+    #[pyo3(signature = (endpoint, cortical_ids = None))]
+    fn new(endpoint: &str, cortical_ids: Option<Vec<PyCorticalID>>) -> PyResult<Self> {
+        PyVisualizationSubscriber::connect(endpoint, cortical_ids)
+    }
+
+    /// Receive the next neuron activity snapshot, waiting up to timeout seconds (forever if None).
+    /// Returns None if no snapshot arrived in time. Non-blocking by default
+    #[pyo3(signature = (timeout = Some(0.0)))]
+    fn receive(&mut self, py: Python<'_>, timeout: Option<f64>) -> PyResult<Option<Py<PyCorticalMappedXYZPNeuronVoxels>>> {
+        match self.receive_payload(py, timeout)? {
+            Some(payload) => Ok(Some(self.decode_snapshot(py, &payload)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Receive the most recent queued snapshot, discarding older ones (non-blocking, returns None if no data).
+    /// Useful for displays that cannot keep up with the burst rate
+    fn receive_latest(&mut self, py: Python<'_>) -> PyResult<Option<Py<PyCorticalMappedXYZPNeuronVoxels>>> {
+        let mut latest_payload = None;
+        while let Some(payload) = self.receive_payload(py, Some(0.0))? {
+            latest_payload = Some(payload);
+        }
+        match latest_payload {
+            Some(payload) => Ok(Some(self.decode_snapshot(py, &payload)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Only keep the given cortical areas in snapshots, or all of them if None
+    #[pyo3(signature = (cortical_ids))]
+    fn set_cortical_filter(&mut self, cortical_ids: Option<Vec<PyCorticalID>>) {
+        self.cortical_filter = cortical_ids.map(|ids| ids.into_iter().map(|id| id.inner).collect());
+    }
+
+    #[getter]
+    fn cortical_filter(&self) -> Option<Vec<PyCorticalID>> {
+        self.cortical_filter.as_ref().map(|ids| ids.iter().map(|id| PyCorticalID::new_from_rust(*id)).collect())
+    }
+
+    #[getter]
+    fn endpoint(&self) -> String {
+        self.endpoint.clone()
+    }
+
+    #[getter]
+    fn snapshots_received(&self) -> usize {
+        self.snapshots_received
+    }
+
+    #[getter]
+    fn is_closed(&self) -> bool {
+        self.socket.is_none()
+    }
+
+    fn close(&mut self) {
+        self.socket = None;
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Waits for the next snapshot. Iteration ends once the subscriber is closed
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Py<PyCorticalMappedXYZPNeuronVoxels>> {
+        if self.socket.is_none() {
+            return Err(PyStopIteration::new_err(()));
+        }
+        match self.receive(py, None)? {
+            Some(snapshot) => Ok(snapshot),
+            None => Err(PyStopIteration::new_err(())),
+        }
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __exit__(&mut self, _exc_type: Py<PyAny>, _exc_value: Py<PyAny>, _traceback: Py<PyAny>) -> bool {
+        self.close();
+        false
+    }
+
+    fn __repr__(&self) -> String {
+        format!("VisualizationSubscriber(endpoint={}, closed={})", self.endpoint, self.socket.is_none())
+    }
+}
@@ -0,0 +1,660 @@
+/*
+ * JSON Schema of the capabilities documents exported by a ConnectorAgent, and validation against it
+ */
+
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use feagi_data_structures::{motor_cortical_units, sensor_cortical_units};
+use feagi_data_structures::genomic::cortical_area::io_cortical_area_configuration_flag::{FrameChangeHandling, PercentageNeuronPositioning};
+use feagi_sensorimotor::data_pipeline::PipelineStageProperties;
+use feagi_sensorimotor::data_types::descriptors::{ColorChannelLayout, ColorSpace, PercentageChannelDimensionality};
+
+const INPUT_SECTION: &str = "input_units_and_encoder_properties";
+const OUTPUT_SECTION: &str = "output_units_and_decoder_properties";
+const FEEDBACKS_SECTION: &str = "feedbacks";
+
+/// What the unit templates declare about a sensor or motor unit
+struct UnitTemplate {
+    name: &'static str,
+    friendly_name: &'static str,
+    doc: Option<&'static str>,
+    accepted_wrapped_io_data_type: &'static str,
+    cortical_type_parameters: &'static [&'static str],
+    allowed_frame_change_handling: Option<&'static [&'static str]>,
+}
+
+macro_rules! unit_templates {
+    (
+        $unit_enum:ident {
+            $(
+                $(#[doc = $doc:expr])?
+                $cortical_type_key_name:ident => {
+                    friendly_name: $friendly_name:expr,
+                    accepted_wrapped_io_data_type: $accepted_wrapped_io_data_type:ident,
+                    cortical_id_unit_reference: $cortical_id_unit_reference:expr,
+                    number_cortical_areas: $number_cortical_areas:expr,
+                    cortical_type_parameters: {
+                        $($param_name:ident: $param_type:ty),* $(,)?
+                    },
+                    $(allowed_frame_change_handling: [$($allowed_frame:ident),* $(,)?],)?
+                    cortical_area_properties: {
+                        $($area_index:tt => ($cortical_area_type_expr:expr, relative_position: [$rel_x:expr, $rel_y:expr, $rel_z:expr], channel_dimensions_default: [$dim_default_x:expr, $dim_default_y:expr, $dim_default_z:expr], channel_dimensions_min: [$dim_min_x:expr, $dim_min_y:expr, $dim_min_z:expr], channel_dimensions_max: [$dim_max_x:expr, $dim_max_y:expr, $dim_max_z:expr])),* $(,)?
+                    }
+                }
+            ),* $(,)?
+        }
+    ) => {
+        vec![
+            $(
+                UnitTemplate {
+                    name: stringify!($cortical_type_key_name),
+                    friendly_name: $friendly_name,
+                    doc: None $(.or(Some($doc)))?,
+                    accepted_wrapped_io_data_type: stringify!($accepted_wrapped_io_data_type),
+                    cortical_type_parameters: &[$(stringify!($param_name)),*],
+                    allowed_frame_change_handling: None $(.or(Some(&[$(stringify!($allowed_frame)),*])))?,
+                }
+            ),*
+        ]
+    };
+}
+
+//region Schema
+
+/// Schema accepting only the serialized forms of the given enum variants
+fn enum_schema<T: Serialize>(variants: &[T]) -> Value {
+    json!({ "enum": variants.iter().map(|variant| serde_json::to_value(variant).unwrap_or(Value::Null)).collect::<Vec<_>>() })
+}
+
+fn definition_ref(name: &str) -> Value {
+    json!({ "$ref": format!("#/definitions/{}", name) })
+}
+
+/// Schema of an externally tagged coder properties variant, such as {"MiscData": {...}}
+fn tagged_schema(variant: &str, contents: Value) -> Value {
+    json!({
+        "type": "object",
+        "required": [variant],
+        "additionalProperties": false,
+        "properties": { variant: contents },
+    })
+}
+
+/// Schema of a fixed length array whose items each have their own schema
+fn tuple_schema(items: Vec<Value>) -> Value {
+    json!({ "type": "array", "minItems": items.len(), "maxItems": items.len(), "items": items })
+}
+
+/// Shared schemas of the descriptors and enums used by several units
+fn definitions() -> Value {
+    let resolution = json!({ "type": "integer", "minimum": 1, "maximum": u32::MAX });
+    let image_resolution = json!({
+        "type": "object",
+        "required": ["width", "height"],
+        "properties": { "width": resolution, "height": resolution },
+    });
+    let segment_names = ["lower_left", "lower_middle", "lower_right", "middle_left", "center", "middle_right", "upper_left", "upper_middle", "upper_right"];
+
+    json!({
+        "neuron_depth": {
+            "description": "Number of neurons along the z axis of a channel",
+            "type": "object",
+            "required": ["value"],
+            "properties": { "value": resolution },
+        },
+        "frame_change_handling": enum_schema(&[FrameChangeHandling::Absolute, FrameChangeHandling::Incremental]),
+        "percentage_neuron_positioning": enum_schema(&[PercentageNeuronPositioning::Linear, PercentageNeuronPositioning::Fractional]),
+        "color_space": enum_schema(&[ColorSpace::Linear, ColorSpace::Gamma]),
+        "color_channel_layout": enum_schema(&[ColorChannelLayout::GrayScale, ColorChannelLayout::RG, ColorChannelLayout::RGB, ColorChannelLayout::RGBA]),
+        "image_resolution": image_resolution,
+        "image_frame_properties": {
+            "type": "object",
+            "required": ["image_resolution", "color_space", "color_channel_layout"],
+            "properties": {
+                "image_resolution": definition_ref("image_resolution"),
+                "color_space": definition_ref("color_space"),
+                "color_channel_layout": definition_ref("color_channel_layout"),
+            },
+        },
+        "segmented_image_frame_properties": {
+            "type": "object",
+            "required": ["segment_xy_resolutions", "center_color_channel", "peripheral_color_channels", "color_space"],
+            "properties": {
+                "segment_xy_resolutions": {
+                    "type": "object",
+                    "required": segment_names,
+                    "properties": segment_names.iter()
+                        .map(|segment_name| (segment_name.to_string(), definition_ref("image_resolution")))
+                        .collect::<Map<String, Value>>(),
+                },
+                "center_color_channel": definition_ref("color_channel_layout"),
+                "peripheral_color_channels": definition_ref("color_channel_layout"),
+                "color_space": definition_ref("color_space"),
+            },
+        },
+        "misc_data_dimensions": {
+            "type": "object",
+            "required": ["width", "height", "depth"],
+            "properties": { "width": resolution, "height": resolution, "depth": resolution },
+        },
+        "device_property_value": {
+            "type": "object",
+            "required": ["type", "value"],
+            "properties": { "type": { "enum": ["String", "Integer", "Float", "Dictionary"] } },
+        },
+        "pipeline_stage": {
+            "description": "Properties of a pipeline stage, keyed by the stage type, such as {\"ImageFrameProcessor\": {...}}",
+            "type": "object",
+            "minProperties": 1,
+            "maxProperties": 1,
+        },
+        "device_grouping": {
+            "description": "A single channel of a registered device group",
+            "type": "object",
+            "required": ["device_properties", "pipeline_stages"],
+            "properties": {
+                "friendly_name": { "type": ["string", "null"] },
+                "channel_index_override": { "type": ["integer", "null"], "minimum": 0, "maximum": u32::MAX },
+                "device_properties": { "type": "object", "additionalProperties": definition_ref("device_property_value") },
+                "pipeline_stages": { "type": "array", "items": definition_ref("pipeline_stage") },
+            },
+        },
+    })
+}
+
+/// Schema of the encoder / decoder properties a unit is registered with, as determined by its wrapped data type.
+/// Percentages such as "SignedPercentage_3D" are encoded as their sign and channel dimensionality
+fn coder_properties_schema(accepted_wrapped_io_data_type: &str) -> Value {
+    let (signed, unsigned_data_type) = accepted_wrapped_io_data_type.strip_prefix("Signed")
+        .map_or((false, accepted_wrapped_io_data_type), |unsigned_data_type| (true, unsigned_data_type));
+    let dimensionality = match unsigned_data_type {
+        "Percentage" => Some(PercentageChannelDimensionality::D1),
+        "Percentage_2D" => Some(PercentageChannelDimensionality::D2),
+        "Percentage_3D" => Some(PercentageChannelDimensionality::D3),
+        "Percentage_4D" => Some(PercentageChannelDimensionality::D4),
+        _ => None,
+    };
+    if let Some(dimensionality) = dimensionality {
+        return tagged_schema("Percentage", tuple_schema(vec![
+            definition_ref("neuron_depth"),
+            definition_ref("percentage_neuron_positioning"),
+            json!({ "const": signed }),
+            json!({ "const": dimensionality }),
+        ]));
+    }
+
+    match accepted_wrapped_io_data_type {
+        "Boolean" => json!({ "const": "Boolean" }),
+        "ImageFrame" => tagged_schema("CartesianPlane", definition_ref("image_frame_properties")),
+        "SegmentedImageFrame" => tagged_schema("SegmentedImageFrame", definition_ref("segmented_image_frame_properties")),
+        "MiscData" => tagged_schema("MiscData", definition_ref("misc_data_dimensions")),
+        "GazeProperties" => tagged_schema("GazeProperties", tuple_schema(vec![
+            definition_ref("neuron_depth"), // Eccentricity
+            definition_ref("neuron_depth"), // Modulation
+            definition_ref("percentage_neuron_positioning"),
+        ])),
+        "ImageFilteringSettings" => tagged_schema("ImageFilteringSettings", tuple_schema(vec![
+            definition_ref("neuron_depth"), // Brightness
+            definition_ref("neuron_depth"), // Contrast
+            definition_ref("neuron_depth"), // Difference
+            definition_ref("percentage_neuron_positioning"),
+        ])),
+        _ => json!({ "description": format!("Coder properties of {} data", accepted_wrapped_io_data_type) }),
+    }
+}
+
+/// Schema of the registrations of a unit: an array of [unit definition, coder properties] pairs, one per group
+fn unit_schema(unit: &UnitTemplate) -> Value {
+    let io_configuration_flags: Map<String, Value> = unit.cortical_type_parameters.iter()
+        .map(|parameter| {
+            let parameter_schema = match (*parameter, unit.allowed_frame_change_handling) {
+                ("frame_change_handling", Some(allowed_frame_change_handling)) => json!({ "enum": allowed_frame_change_handling }),
+                ("frame_change_handling" | "percentage_neuron_positioning", _) => definition_ref(parameter),
+                _ => json!({}),
+            };
+            (parameter.to_string(), parameter_schema)
+        })
+        .collect();
+    let unit_definition = json!({
+        "type": "object",
+        "required": ["cortical_unit_index", "io_configuration_flags", "device_grouping"],
+        "properties": {
+            "cortical_unit_index": { "description": "Group of the unit", "type": "integer", "minimum": 0, "maximum": u8::MAX },
+            "friendly_name": { "type": ["string", "null"] },
+            "io_configuration_flags": {
+                "type": "object",
+                "required": unit.cortical_type_parameters,
+                "properties": io_configuration_flags,
+            },
+            "device_grouping": { "type": "array", "minItems": 1, "items": definition_ref("device_grouping") },
+        },
+    });
+
+    let description = match unit.doc {
+        Some(doc) => format!("{}: {}", unit.friendly_name, doc),
+        None => unit.friendly_name.to_string(),
+    };
+    json!({
+        "description": description,
+        "type": "array",
+        "items": tuple_schema(vec![unit_definition, coder_properties_schema(unit.accepted_wrapped_io_data_type)]),
+    })
+}
+
+fn section_schema(units: Vec<UnitTemplate>) -> Value {
+    json!({
+        "type": "object",
+        "additionalProperties": false,
+        "properties": units.iter()
+            .map(|unit| (unit.name.to_string(), unit_schema(unit)))
+            .collect::<Map<String, Value>>(),
+    })
+}
+
+/// JSON Schema (draft-07) of the documents exported by export_capabilities_json, covering every sensor and
+/// motor unit of the unit templates
+pub(crate) fn capabilities_json_schema() -> Value {
+    let sensor_units: Vec<UnitTemplate> = sensor_cortical_units!(unit_templates);
+    let motor_units: Vec<UnitTemplate> = motor_cortical_units!(unit_templates);
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "FEAGI connector capabilities",
+        "description": "Device registrations of a ConnectorAgent, as exported by export_capabilities_json",
+        "type": "object",
+        "required": [FEEDBACKS_SECTION, INPUT_SECTION, OUTPUT_SECTION],
+        "properties": {
+            FEEDBACKS_SECTION: {
+                "type": "object",
+                "required": ["registered_feedbacks"],
+                "properties": { "registered_feedbacks": { "type": "array" } },
+            },
+            INPUT_SECTION: section_schema(sensor_units),
+            OUTPUT_SECTION: section_schema(motor_units),
+        },
+        "definitions": definitions(),
+    })
+}
+
+//endregion
+
+//region Validation
+
+/// JSON path of a property of the object at the given path
+fn property_path(path: &str, key: &str) -> String {
+    let is_identifier = !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if is_identifier {
+        format!("{}.{}", path, key)
+    } else {
+        format!("{}[{}]", path, Value::String(key.to_string()))
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(number) if number.is_i64() || number.is_u64() => "integer",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn matches_type(value: &Value, expected_type: &str) -> bool {
+    match expected_type {
+        "number" => value.is_number(),
+        expected_type => type_name(value) == expected_type,
+    }
+}
+
+fn format_choices(choices: &[Value]) -> String {
+    choices.iter().map(Value::to_string).collect::<Vec<_>>().join(", ")
+}
+
+/// Checks a value against the subset of JSON Schema used by capabilities_json_schema, appending a
+/// "<JSON path>: <problem>" message per problem found
+fn validate_against(value: &Value, schema: &Value, root_schema: &Value, path: &str, errors: &mut Vec<String>) {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        match reference.strip_prefix("#/definitions/").and_then(|name| root_schema["definitions"].get(name)) {
+            Some(definition) => validate_against(value, definition, root_schema, path, errors),
+            None => errors.push(format!("{}: schema references unknown definition {}", path, reference)),
+        }
+        return;
+    }
+
+    if let Some(expected_types) = schema.get("type") {
+        let expected_types: Vec<&str> = match expected_types {
+            Value::Array(expected_types) => expected_types.iter().filter_map(Value::as_str).collect(),
+            expected_type => expected_type.as_str().into_iter().collect(),
+        };
+        if !expected_types.iter().any(|expected_type| matches_type(value, expected_type)) {
+            errors.push(format!("{}: expected {}, found {}", path, expected_types.join(" or "), type_name(value)));
+            return;
+        }
+    }
+    if let Some(expected) = schema.get("const") {
+        if value != expected {
+            errors.push(format!("{}: expected {}, found {}", path, expected, value));
+            return;
+        }
+    }
+    if let Some(choices) = schema.get("enum").and_then(Value::as_array) {
+        if !choices.contains(value) {
+            errors.push(format!("{}: expected one of {}, found {}", path, format_choices(choices), value));
+            return;
+        }
+    }
+
+    if let Some(number) = value.as_f64() {
+        if let Some(minimum) = schema.get("minimum").and_then(Value::as_f64).filter(|minimum| number < *minimum) {
+            errors.push(format!("{}: expected at least {}, found {}", path, minimum, value));
+        }
+        if let Some(maximum) = schema.get("maximum").and_then(Value::as_f64).filter(|maximum| number > *maximum) {
+            errors.push(format!("{}: expected at most {}, found {}", path, maximum, value));
+        }
+    }
+
+    match value {
+        Value::Object(object) => validate_object(object, schema, root_schema, path, errors),
+        Value::Array(items) => validate_array(items, schema, root_schema, path, errors),
+        _ => {}
+    }
+}
+
+fn validate_object(object: &Map<String, Value>, schema: &Value, root_schema: &Value, path: &str, errors: &mut Vec<String>) {
+    for required_key in schema.get("required").and_then(Value::as_array).into_iter().flatten().filter_map(Value::as_str) {
+        if !object.contains_key(required_key) {
+            errors.push(format!("{}: missing required property \"{}\"", path, required_key));
+        }
+    }
+    if let Some(minimum) = schema.get("minProperties").and_then(Value::as_u64).filter(|minimum| (object.len() as u64) < *minimum) {
+        errors.push(format!("{}: expected at least {} properties, found {}", path, minimum, object.len()));
+    }
+    if let Some(maximum) = schema.get("maxProperties").and_then(Value::as_u64).filter(|maximum| (object.len() as u64) > *maximum) {
+        errors.push(format!("{}: expected at most {} properties, found {}", path, maximum, object.len()));
+    }
+
+    let properties = schema.get("properties").and_then(Value::as_object);
+    for (key, property_value) in object {
+        let property_path = property_path(path, key);
+        match (properties.and_then(|properties| properties.get(key)), schema.get("additionalProperties")) {
+            (Some(property_schema), _) => validate_against(property_value, property_schema, root_schema, &property_path, errors),
+            (None, Some(Value::Bool(false))) => {
+                let known_keys: Vec<Value> = properties.into_iter().flat_map(Map::keys).cloned().map(Value::String).collect();
+                errors.push(format!("{}: unexpected property, expected one of {}", property_path, format_choices(&known_keys)));
+            }
+            (None, Some(additional_schema)) if additional_schema.is_object() => {
+                validate_against(property_value, additional_schema, root_schema, &property_path, errors)
+            }
+            (None, _) => {}
+        }
+    }
+}
+
+fn validate_array(items: &[Value], schema: &Value, root_schema: &Value, path: &str, errors: &mut Vec<String>) {
+    if let Some(minimum) = schema.get("minItems").and_then(Value::as_u64).filter(|minimum| (items.len() as u64) < *minimum) {
+        errors.push(format!("{}: expected at least {} items, found {}", path, minimum, items.len()));
+    }
+    if let Some(maximum) = schema.get("maxItems").and_then(Value::as_u64).filter(|maximum| (items.len() as u64) > *maximum) {
+        errors.push(format!("{}: expected at most {} items, found {}", path, maximum, items.len()));
+    }
+
+    match schema.get("items") {
+        Some(Value::Array(item_schemas)) => {
+            for (index, (item, item_schema)) in items.iter().zip(item_schemas).enumerate() {
+                validate_against(item, item_schema, root_schema, &format!("{}[{}]", path, index), errors);
+            }
+        }
+        Some(item_schema) => {
+            for (index, item) in items.iter().enumerate() {
+                validate_against(item, item_schema, root_schema, &format!("{}[{}]", path, index), errors);
+            }
+        }
+        None => {}
+    }
+}
+
+/// Pipeline stage properties are too varied to describe in the schema, so each one that passed it is checked by
+/// deserializing it
+fn validate_pipeline_stages(document: &Value, errors: &mut Vec<String>) {
+    for section in [INPUT_SECTION, OUTPUT_SECTION] {
+        let Some(units) = document.get(section).and_then(Value::as_object) else {
+            continue;
+        };
+        for (unit_key, unit_definitions) in units {
+            let unit_path = property_path(&property_path("$", section), unit_key);
+            for (definition_index, unit_and_coder) in unit_definitions.as_array().into_iter().flatten().enumerate() {
+                let channels = unit_and_coder.get(0)
+                    .and_then(|unit_definition| unit_definition.get("device_grouping"))
+                    .and_then(Value::as_array);
+                for (channel_index, channel) in channels.into_iter().flatten().enumerate() {
+                    let stages = channel.get("pipeline_stages").and_then(Value::as_array);
+                    for (stage_index, stage) in stages.into_iter().flatten().enumerate() {
+                        if let Err(error) = serde_json::from_value::<PipelineStageProperties>(stage.clone()) {
+                            errors.push(format!(
+                                "{}[{}][0].device_grouping[{}].pipeline_stages[{}]: invalid pipeline stage: {}",
+                                unit_path, definition_index, channel_index, stage_index, error
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Validates a capabilities document against capabilities_json_schema, returning a "<JSON path>: <problem>"
+/// message per problem found. Without require_all_sections, the feedbacks, sensor and motor sections may be
+/// left out, as when only updating some registrations
+pub(crate) fn validate_capabilities_document(document: &Value, require_all_sections: bool) -> Vec<String> {
+    let mut schema = capabilities_json_schema();
+    if !require_all_sections {
+        if let Some(schema) = schema.as_object_mut() {
+            schema.remove("required");
+        }
+    }
+
+    let mut errors = Vec::new();
+    validate_against(document, &schema, &schema, "$", &mut errors);
+    if errors.is_empty() {
+        validate_pipeline_stages(document, &mut errors);
+    }
+    errors
+}
+
+//endregion
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use feagi_agent::sdk::ConnectorAgent;
+    use feagi_data_structures::FeagiDataError;
+    use feagi_data_structures::genomic::cortical_area::descriptors::{CorticalChannelCount, NeuronDepth};
+    use feagi_sensorimotor::caching::{MotorDeviceCache, SensorDeviceCache};
+    use feagi_sensorimotor::data_types::{GazeProperties, Percentage3D};
+    use feagi_sensorimotor::data_types::descriptors::{
+        ImageFrameProperties, ImageXYResolution, MiscDataDimensions, SegmentedImageFrameProperties, SegmentedXYImageResolutions,
+    };
+
+    const GROUP: u8 = 2;
+    const CHANNELS: u32 = 2;
+
+    fn neuron_depth() -> Result<NeuronDepth, FeagiDataError> {
+        NeuronDepth::new(10)
+    }
+
+    fn misc_data_dimensions() -> Result<MiscDataDimensions, FeagiDataError> {
+        MiscDataDimensions::new(4, 4, 2)
+    }
+
+    fn image_frame_properties() -> Result<ImageFrameProperties, FeagiDataError> {
+        ImageFrameProperties::new(ImageXYResolution::new(16, 12)?, ColorSpace::Gamma, ColorChannelLayout::RGB)
+    }
+
+    fn segmented_image_frame_properties() -> Result<SegmentedImageFrameProperties, FeagiDataError> {
+        let resolutions = SegmentedXYImageResolutions::create_with_same_sized_peripheral(
+            ImageXYResolution::new(8, 8)?,
+            ImageXYResolution::new(4, 4)?,
+        );
+        Ok(SegmentedImageFrameProperties::new(resolutions, ColorChannelLayout::RGB, ColorChannelLayout::GrayScale, ColorSpace::Gamma))
+    }
+
+    /// Registers a group of a sensor unit with the register function its wrapped data type generates
+    macro_rules! register_sensor {
+        ($cache:ident, $unit:ident, Boolean) => {
+            ::paste::paste! { $cache.[<$unit:snake _register>](GROUP.into(), CorticalChannelCount::new(CHANNELS)?)? }
+        };
+        ($cache:ident, $unit:ident, MiscData) => {
+            ::paste::paste! { $cache.[<$unit:snake _register>](
+                GROUP.into(), CorticalChannelCount::new(CHANNELS)?, FrameChangeHandling::Absolute, misc_data_dimensions()?,
+            )? }
+        };
+        ($cache:ident, $unit:ident, ImageFrame) => {
+            ::paste::paste! { $cache.[<$unit:snake _register>](
+                GROUP.into(), CorticalChannelCount::new(CHANNELS)?, FrameChangeHandling::Absolute, image_frame_properties()?,
+            )? }
+        };
+        ($cache:ident, $unit:ident, SegmentedImageFrame) => {
+            ::paste::paste! { $cache.[<$unit:snake _register>](
+                GROUP.into(), CorticalChannelCount::new(CHANNELS)?, FrameChangeHandling::Absolute, image_frame_properties()?,
+                segmented_image_frame_properties()?, GazeProperties::new_from_3d(Percentage3D::try_from((0.5, 0.5, 0.5))?),
+            )? }
+        };
+        ($cache:ident, $unit:ident, $percentage_data_type:ident) => {
+            ::paste::paste! { $cache.[<$unit:snake _register>](
+                GROUP.into(), CorticalChannelCount::new(CHANNELS)?, FrameChangeHandling::Absolute, neuron_depth()?,
+                PercentageNeuronPositioning::Linear,
+            )? }
+        };
+    }
+
+    /// Registers a group of a motor unit with the register function its wrapped data type generates
+    macro_rules! register_motor {
+        ($cache:ident, $unit:ident, GazeProperties) => {
+            ::paste::paste! { $cache.[<$unit:snake _register>](
+                GROUP.into(), CorticalChannelCount::new(CHANNELS)?, FrameChangeHandling::Absolute, neuron_depth()?,
+                neuron_depth()?, PercentageNeuronPositioning::Linear,
+            )? }
+        };
+        ($cache:ident, $unit:ident, MiscData) => {
+            ::paste::paste! { $cache.[<$unit:snake _register>](
+                GROUP.into(), CorticalChannelCount::new(CHANNELS)?, FrameChangeHandling::Absolute, misc_data_dimensions()?,
+            )? }
+        };
+        ($cache:ident, $unit:ident, ImageFrame) => {
+            ::paste::paste! { $cache.[<$unit:snake _register>](
+                GROUP.into(), CorticalChannelCount::new(CHANNELS)?, FrameChangeHandling::Absolute, image_frame_properties()?,
+            )? }
+        };
+        ($cache:ident, $unit:ident, $percentage_data_type:ident) => {
+            ::paste::paste! { $cache.[<$unit:snake _register>](
+                GROUP.into(), CorticalChannelCount::new(CHANNELS)?, FrameChangeHandling::Absolute, neuron_depth()?,
+                PercentageNeuronPositioning::Linear,
+            )? }
+        };
+    }
+
+    /// A function registering a group of every unit in the unit templates, for use as their callback
+    macro_rules! register_every_unit {
+        ($cache_type:ty, $register_unit:ident, $unit_enum:ident {
+            $(
+                $(#[doc = $doc:expr])?
+                $unit:ident => {
+                    friendly_name: $friendly_name:expr,
+                    accepted_wrapped_io_data_type: $accepted_wrapped_io_data_type:ident,
+                    $($unit_properties:tt)*
+                }
+            ),* $(,)?
+        }) => {
+            |cache: &mut $cache_type| -> Result<(), FeagiDataError> {
+                $( $register_unit!(cache, $unit, $accepted_wrapped_io_data_type); )*
+                Ok(())
+            }
+        };
+    }
+
+    macro_rules! register_every_sensor {
+        ($($templates:tt)*) => { register_every_unit!(SensorDeviceCache, register_sensor, $($templates)*) };
+    }
+
+    macro_rules! register_every_motor {
+        ($($templates:tt)*) => { register_every_unit!(MotorDeviceCache, register_motor, $($templates)*) };
+    }
+
+    /// Capabilities document of an agent with a group of every sensor and motor unit registered
+    fn export_of_every_unit() -> Value {
+        let agent = ConnectorAgent::new();
+        sensor_cortical_units!(register_every_sensor)(&mut agent.get_sensor_cache()).unwrap();
+        motor_cortical_units!(register_every_motor)(&mut agent.get_motor_cache()).unwrap();
+        agent.export_device_registrations_as_config_json().unwrap()
+    }
+
+    fn unit_keys(value: &Value) -> Vec<&String> {
+        value.as_object().unwrap().keys().collect()
+    }
+
+    #[test]
+    fn export_of_every_unit_validates() {
+        let document = export_of_every_unit();
+        let schema = capabilities_json_schema();
+        for section in [INPUT_SECTION, OUTPUT_SECTION] {
+            assert_eq!(unit_keys(&document[section]), unit_keys(&schema["properties"][section]["properties"]));
+        }
+
+        assert_eq!(validate_capabilities_document(&document, true), Vec::<String>::new());
+    }
+
+    #[test]
+    fn problems_are_reported_at_their_paths() {
+        let mut document = export_of_every_unit();
+        document[INPUT_SECTION]["Vision"][0][0]["cortical_unit_index"] = json!("2");
+        document[INPUT_SECTION]["TextEnglishInput"][0][0]["io_configuration_flags"]["frame_change_handling"] = json!("Incremental");
+        document[OUTPUT_SECTION]["RotaryMotor"][0][0]["cortical_unit_index"] = json!(300);
+        document[OUTPUT_SECTION]["Gaze"][0][0]["device_grouping"] = json!([]);
+        document[OUTPUT_SECTION]["MiscData"][0][1] = json!({ "MiscData": "4x4x2" });
+        document[INPUT_SECTION]["Telepathy"] = json!([]);
+        document.as_object_mut().unwrap().remove(FEEDBACKS_SECTION);
+
+        let errors = validate_capabilities_document(&document, true);
+        assert_eq!(errors.len(), 7, "{:#?}", errors);
+        assert!(errors.contains(&"$: missing required property \"feedbacks\"".to_string()));
+        assert!(errors.contains(&"$.input_units_and_encoder_properties.Vision[0][0].cortical_unit_index: expected integer, found string".to_string()));
+        assert!(errors.contains(&"$.input_units_and_encoder_properties.TextEnglishInput[0][0].io_configuration_flags.frame_change_handling: expected one of \"Absolute\", found \"Incremental\"".to_string()));
+        assert!(errors.contains(&"$.output_units_and_decoder_properties.RotaryMotor[0][0].cortical_unit_index: expected at most 255, found 300".to_string()));
+        assert!(errors.contains(&"$.output_units_and_decoder_properties.Gaze[0][0].device_grouping: expected at least 1 items, found 0".to_string()));
+        assert!(errors.contains(&"$.output_units_and_decoder_properties.MiscData[0][1].MiscData: expected object, found string".to_string()));
+        assert!(errors.iter().any(|error| error.starts_with("$.input_units_and_encoder_properties.Telepathy: unexpected property, expected one of ")));
+
+        // Left out sections are only a problem when all of them are required
+        document[INPUT_SECTION].as_object_mut().unwrap().remove("Telepathy");
+        let remaining_errors: Vec<String> = errors.into_iter()
+            .filter(|error| !error.starts_with("$: ") && !error.contains("Telepathy"))
+            .collect();
+        assert_eq!(validate_capabilities_document(&document, false), remaining_errors);
+    }
+
+    #[test]
+    fn invalid_pipeline_stages_are_reported_at_their_paths() {
+        let mut document = export_of_every_unit();
+        document[INPUT_SECTION]["AnalogGPIO"][0][0]["device_grouping"][1]["pipeline_stages"] = json!([{ "NoSuchStage": {} }]);
+        document[INPUT_SECTION]["Vision"][0][0]["cortical_unit_index"] = json!("2");
+
+        // Pipeline stages are only checked once the document matches the schema
+        assert_eq!(validate_capabilities_document(&document, true).len(), 1);
+
+        document[INPUT_SECTION]["Vision"][0][0]["cortical_unit_index"] = json!(GROUP);
+        let errors = validate_capabilities_document(&document, true);
+        assert_eq!(errors.len(), 1, "{:#?}", errors);
+        assert!(errors[0].starts_with(
+            "$.input_units_and_encoder_properties.AnalogGPIO[0][0].device_grouping[1].pipeline_stages[0]: invalid pipeline stage: "
+        ));
+    }
+
+    #[test]
+    fn keys_that_are_not_identifiers_are_quoted() {
+        assert_eq!(property_path("$", "Vision"), "$.Vision");
+        assert_eq!(property_path("$.units", "front camera"), "$.units[\"front camera\"]");
+        assert_eq!(property_path("$", ""), "$[\"\"]");
+    }
+}
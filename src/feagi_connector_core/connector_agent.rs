@@ -4,23 +4,30 @@ use std::time::Instant;
 use pyo3::{pyclass, pymethods, PyResult};
 use pyo3::types::{PyByteArray, PyBytes};
 use pyo3::prelude::*;
-use feagi_data_structures::{motor_cortical_units, sensor_cortical_units, FeagiDataError};
+use numpy::{AllowTypeChange, PyArrayLikeDyn};
+use feagi_data_structures::{motor_cortical_units, sensor_cortical_units, FeagiDataError, FeagiSignalIndex};
 use feagi_data_structures::genomic::cortical_area::descriptors::*;
+use feagi_data_structures::genomic::{MotorCorticalUnit, SensoryCorticalUnit};
 use feagi_data_structures::genomic::cortical_area::io_cortical_area_configuration_flag::FrameChangeHandling;
 use feagi_data_structures::genomic::cortical_area::io_cortical_area_configuration_flag::PercentageNeuronPositioning;
 use feagi_sensorimotor::caching::{MotorDeviceCache, SensorDeviceCache};
 use feagi_agent::sdk::ConnectorAgent;
+use feagi_serialization::FeagiByteContainer;
 use feagi_sensorimotor::data_pipeline::PipelineStagePropertyIndex;
 use feagi_sensorimotor::data_types::*;
 use feagi_sensorimotor::data_types::descriptors::*;
 use feagi_sensorimotor::wrapped_io_data::WrappedIOData;
 use crate::feagi_connector_core::data_types::descriptors::PyMiscDataDimensions;
-use crate::{create_pyclass_no_clone, __base_py_class_shared};
+use crate::feagi_connector_core::capabilities_schema::{capabilities_json_schema, validate_capabilities_document};
+use crate::feagi_connector_core::device_registrations::{read_device_registrations, rebuild_group, update_registrations_from, PyMotorRegistration, PySensorRegistration};
+use crate::feagi_connector_core::sensor_byte_export::{PySensorBytesView, SensorByteExport};
+use crate::feagi_connector_core::sensor_channel_arrays::channel_array_to_wrapped_io_data;
+use crate::feagi_connector_core::traffic_recording::{PyTrafficRecorder, TrafficDirection, TrafficRecorderSlot};
 use crate::py_error::PyFeagiError;
 use crate::feagi_connector_core::data_types::descriptors::*;
 use crate::feagi_connector_core::data_pipeline::pipeline_stage_properties::PyPipelineStageProperties;
 use crate::feagi_connector_core::data_types::*;
-use crate::feagi_connector_core::wrapped_io_data::py_any_to_wrapped_io_data;
+use crate::feagi_connector_core::wrapped_io_data::{py_any_to_wrapped_io_data, wrapped_io_data_to_py_object};
 use crate::feagi_data_structures::genomic::cortical_area::*;
 
 type Pybool = bool; // ALL HAIL THE LOAD BEARING BOOLEAN
@@ -100,6 +107,31 @@ macro_rules! sensor_unit_functions {
                     Ok(expected_data.into())
                 }
 
+                /// Removes the group, so its cortical areas are no longer encoded, along with the feedbacks
+                /// targeting it. Other sensor channels keep their cached value unless they have pipeline stages
+                pub fn [<sensor_ $cortical_type_key_name:snake _unregister>](
+                    &mut self,
+                    py: Python<'_>,
+                    group: u8,
+                ) -> PyResult<()>
+                {
+                    self.rebuild_sensor_group(py, SensoryCorticalUnit::$cortical_type_key_name, group, None)
+                }
+
+                /// Changes the number of channels of the group, whose cached values are reset. Added channels have
+                /// no pipeline stages, and feedbacks targeting removed channels are dropped. Other sensor channels
+                /// keep their cached value unless they have pipeline stages
+                pub fn [<sensor_ $cortical_type_key_name:snake _resize>](
+                    &mut self,
+                    py: Python<'_>,
+                    group: u8,
+                    number_channels: u32,
+                ) -> PyResult<()>
+                {
+                    let number_channels: CorticalChannelCount = number_channels.try_into().map_err(PyFeagiError::from)?;
+                    self.rebuild_sensor_group(py, SensoryCorticalUnit::$cortical_type_key_name, group, Some(number_channels))
+                }
+
                 pub fn [<sensor_ $cortical_type_key_name:snake _get_single_stage_properties>](
                     &mut self,
                     py: Python<'_>,
@@ -220,6 +252,50 @@ macro_rules! sensor_unit_functions {
              }
         }
     };
+
+    // Helper macro to generate writing all channels of a group at once from a numpy array, for float based data types
+    (@generate_channel_array_write_function
+        $cortical_type_key_name:ident,
+        $wrapped_data_type:ident
+    ) => {
+        ::paste::paste! {
+
+            #[pymethods]
+            impl PyConnectorAgent {
+
+                /// Writes every channel of the group at once, from a float array of shape (channels, dimensions).
+                /// Arrays (or nested sequences) of other numeric types, such as float64, are cast to float32.
+                /// All values are validated before any channel is written
+                pub fn [<sensor_ $cortical_type_key_name:snake _write_channels>](
+                    &mut self,
+                    _py: Python<'_>,
+                    group: u8,
+                    data: PyArrayLikeDyn<'_, f32, AllowTypeChange>,
+                ) -> PyResult<()> {
+                    const SENSOR_TYPE: SensoryCorticalUnit = SensoryCorticalUnit::$cortical_type_key_name;
+
+                    let group: CorticalUnitIndex = group.into();
+                    let channel_data = channel_array_to_wrapped_io_data::<$wrapped_data_type>(data.as_array()).map_err(PyFeagiError::from)?;
+
+                    let mut sensor_cache = self.get_sensor_cache();
+                    for channel_index in 0..channel_data.len() as u32 {
+                        sensor_cache.verify_existence(SENSOR_TYPE, group, channel_index.into()).map_err(PyFeagiError::from)?;
+                    }
+                    if sensor_cache.verify_existence(SENSOR_TYPE, group, (channel_data.len() as u32).into()).is_ok() {
+                        return Err(PyFeagiError::from(FeagiDataError::BadParameters(format!(
+                            "Data has {} channels, but the group has more! All channels of the group must be written", channel_data.len()
+                        ))).into());
+                    }
+
+                    for (channel_index, data) in channel_data.into_iter().enumerate() {
+                        let channel_index: CorticalChannelIndex = (channel_index as u32).into();
+                        sensor_cache.[<$cortical_type_key_name:snake _write>](group, channel_index, data).map_err(PyFeagiError::from)?;
+                    }
+                    Ok(())
+                }
+            }
+        }
+    };
     //endregion
 
 
@@ -287,6 +363,7 @@ macro_rules! sensor_unit_functions {
         }
 
         sensor_unit_functions!(@generate_similar_functions $sensory_unit, Percentage);
+        sensor_unit_functions!(@generate_channel_array_write_function $sensory_unit, Percentage);
     };
 
     // Arm for WrappedIOType::Percentage_3D
@@ -322,6 +399,7 @@ macro_rules! sensor_unit_functions {
         }
 
         sensor_unit_functions!(@generate_similar_functions $sensory_unit, Percentage3D);
+        sensor_unit_functions!(@generate_channel_array_write_function $sensory_unit, Percentage3D);
     };
 
     // Arm for WrappedIOType::SignedPercentage_4D
@@ -356,6 +434,7 @@ macro_rules! sensor_unit_functions {
         }
 
         sensor_unit_functions!(@generate_similar_functions $sensory_unit, SignedPercentage4D);
+        sensor_unit_functions!(@generate_channel_array_write_function $sensory_unit, SignedPercentage4D);
     };
 
     // Arm for WrappedIOType::SegmentedImageFrame
@@ -532,6 +611,57 @@ macro_rules! motor_unit_functions {
                     Ok(expected_data.into())
                 }
 
+                /// Registers a Python callable that is called with the postprocessed value of the
+                /// given channel every time a decode updates it. Returns the signal index of the callback.
+                /// The callback stays registered if the motor cache is rebuilt, as long as its channel still exists.
+                pub fn [<motor_ $cortical_type_key_name:snake _try_register_motor_callback>](
+                    &mut self,
+                    py: Python<'_>,
+                    group: u8,
+                    channel_index: u32,
+                    callback: Py<PyAny>,
+                ) -> PyResult<u32>
+                {
+                    let registration = MotorCallbackRegistration {
+                        unit: MotorCorticalUnit::$cortical_type_key_name,
+                        group: group.into(),
+                        channel_index: channel_index.into(),
+                        callback,
+                        register: |motor_cache, group, channel_index, callback| {
+                            motor_cache.[<$cortical_type_key_name:snake _try_register_motor_callback>](group, channel_index, callback)
+                        },
+                    };
+
+                    let signal_index = registration.register_to(py, &mut self.get_motor_cache())?;
+                    self.motor_callbacks.push(registration);
+                    Ok(*signal_index)
+                }
+
+                /// Removes the group, along with the feedbacks targeting it. Python motor callbacks of other groups
+                /// stay registered. Cached motor values are reset, as are sensor channels with pipeline stages
+                pub fn [<motor_ $cortical_type_key_name:snake _unregister>](
+                    &mut self,
+                    py: Python<'_>,
+                    group: u8,
+                ) -> PyResult<()>
+                {
+                    self.rebuild_motor_group(py, MotorCorticalUnit::$cortical_type_key_name, group, None)
+                }
+
+                /// Changes the number of channels of the group. Feedbacks targeting removed channels are dropped,
+                /// while Python motor callbacks of channels that still exist stay registered. Cached motor values
+                /// are reset, as are sensor channels with pipeline stages
+                pub fn [<motor_ $cortical_type_key_name:snake _resize>](
+                    &mut self,
+                    py: Python<'_>,
+                    group: u8,
+                    number_channels: u32,
+                ) -> PyResult<()>
+                {
+                    let number_channels: CorticalChannelCount = number_channels.try_into().map_err(PyFeagiError::from)?;
+                    self.rebuild_motor_group(py, MotorCorticalUnit::$cortical_type_key_name, group, Some(number_channels))
+                }
+
                 pub fn [<motor_ $cortical_type_key_name:snake _get_single_stage_properties>](
                     &mut self,
                     py: Python<'_>,
@@ -881,7 +1011,42 @@ macro_rules! motor_unit_functions {
 
 }
 
-create_pyclass_no_clone!(PyConnectorAgent, ConnectorAgent, "ConnectorAgent");
+// Not created through create_pyclass_no_clone, as the agent needs to track state beyond the wrapped rust struct
+#[pyclass(str)]
+#[pyo3(name = "ConnectorAgent")]
+#[derive(Debug)]
+pub struct PyConnectorAgent {
+    pub inner: ConnectorAgent,
+    pub(crate) sensor_byte_export: SensorByteExport,
+    motor_callbacks: Vec<MotorCallbackRegistration>,
+    pub(crate) traffic_recorder: TrafficRecorderSlot,
+}
+
+type MotorCallbackRegisterFn = fn(&mut MotorDeviceCache, CorticalUnitIndex, CorticalChannelIndex, Box<dyn Fn(&WrappedIOData) + Send + Sync>) -> Result<FeagiSignalIndex, FeagiDataError>;
+
+/// A Python motor callback, kept so it can be registered again whenever the motor cache is rebuilt
+#[derive(Debug)]
+struct MotorCallbackRegistration {
+    unit: MotorCorticalUnit,
+    group: CorticalUnitIndex,
+    channel_index: CorticalChannelIndex,
+    callback: Py<PyAny>,
+    register: MotorCallbackRegisterFn,
+}
+
+impl MotorCallbackRegistration {
+    fn register_to(&self, py: Python<'_>, motor_cache: &mut MotorDeviceCache) -> PyResult<FeagiSignalIndex> {
+        let callback = PyConnectorAgent::wrap_python_motor_callback(py, self.callback.clone_ref(py))?;
+        let signal_index = (self.register)(motor_cache, self.group, self.channel_index, Box::new(callback)).map_err(PyFeagiError::from)?;
+        Ok(signal_index)
+    }
+}
+
+impl std::fmt::Display for PyConnectorAgent {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
 
 impl PyConnectorAgent {
     fn get_sensor_cache(&self) -> MutexGuard<'_, SensorDeviceCache> {
@@ -892,6 +1057,67 @@ impl PyConnectorAgent {
         self.inner.get_motor_cache()
 
     }
+
+    /// Passes the given byte container to the attached traffic recorder, if any and still open
+    fn record_traffic(&self, py: Python<'_>, direction: TrafficDirection, byte_container: &FeagiByteContainer) -> PyResult<()> {
+        self.traffic_recorder.record(py, direction, byte_container)
+    }
+
+    /// Removes (number_channels of None) or resizes a sensor group, see rebuild_group
+    fn rebuild_sensor_group(&mut self, py: Python<'_>, unit: SensoryCorticalUnit, group: u8, number_channels: Option<CorticalChannelCount>) -> PyResult<()> {
+        let unit_key = serde_json::to_value(unit)
+            .map_err(|e| PyFeagiError::from(FeagiDataError::SerializationError(e.to_string())))?;
+        self.rebuild_group(py, "input_units_and_encoder_properties", &unit_key, group, number_channels)
+    }
+
+    /// Removes (number_channels of None) or resizes a motor group, see rebuild_group
+    fn rebuild_motor_group(&mut self, py: Python<'_>, unit: MotorCorticalUnit, group: u8, number_channels: Option<CorticalChannelCount>) -> PyResult<()> {
+        let unit_key = serde_json::to_value(unit)
+            .map_err(|e| PyFeagiError::from(FeagiDataError::SerializationError(e.to_string())))?;
+        self.rebuild_group(py, "output_units_and_decoder_properties", &unit_key, group, number_channels)
+    }
+
+    /// Reimports all registrations with the given group removed or resized, which also rewires the feedbacks
+    /// of channels that still exist. Python motor callbacks are registered again afterward
+    fn rebuild_group(&mut self, py: Python<'_>, section: &str, unit_key: &serde_json::Value, group: u8, number_channels: Option<CorticalChannelCount>) -> PyResult<()> {
+        let unit_key = unit_key.as_str()
+            .ok_or_else(|| PyFeagiError::from(FeagiDataError::InternalError(format!("Unexpected unit key {}", unit_key))))?;
+        let _sensor_bytes_write = self.sensor_byte_export.begin_write().map_err(PyFeagiError::from)?;
+        rebuild_group(&mut self.inner, section, unit_key, group, number_channels).map_err(PyFeagiError::from)?;
+        self.reregister_motor_callbacks(py)
+    }
+
+    /// Registers the Python motor callbacks again after the motor cache was rebuilt.
+    /// Callbacks of channels that no longer exist are dropped
+    fn reregister_motor_callbacks(&mut self, py: Python<'_>) -> PyResult<()> {
+        let mut motor_cache = self.inner.get_motor_cache();
+        self.motor_callbacks.retain(|registration| {
+            motor_cache.verify_existence(registration.unit, registration.group, registration.channel_index).is_ok()
+        });
+        for registration in &self.motor_callbacks {
+            registration.register_to(py, &mut motor_cache)?;
+        }
+        Ok(())
+    }
+
+    /// Wraps a Python callable into a motor callback. The callback runs while the motor cache is
+    /// locked, so it must not call back into the motor functions of the same agent.
+    /// Exceptions raised by the callable are reported as unraisable instead of aborting the decode.
+    fn wrap_python_motor_callback(py: Python<'_>, callback: Py<PyAny>) -> PyResult<impl Fn(&WrappedIOData) + Send + Sync + 'static> {
+        if !callback.bind(py).is_callable() {
+            return Err(PyFeagiError::from(FeagiDataError::BadParameters("Motor callback must be callable!".into())).into());
+        }
+
+        Ok(move |data: &WrappedIOData| {
+            Python::attach(|py| {
+                let result = wrapped_io_data_to_py_object(py, data.clone())
+                    .and_then(|py_data| callback.call1(py, (py_data,)));
+                if let Err(err) = result {
+                    err.write_unraisable(py, Some(callback.bind(py)));
+                }
+            });
+        })
+    }
 }
 
 
@@ -927,6 +1153,9 @@ impl PyConnectorAgent {
     pub fn new() -> Self {
         PyConnectorAgent {
             inner: ConnectorAgent::new(),
+            sensor_byte_export: SensorByteExport::default(),
+            motor_callbacks: Vec::new(),
+            traffic_recorder: TrafficRecorderSlot::default(),
         }
     }
 
@@ -945,24 +1174,110 @@ impl PyConnectorAgent {
             .map_err(Into::into)
     }
     
-    /// Import capabilities from JSON string (devices must be registered first!)
+    /// List every registered sensor group, with its channels, cortical IDs and pipeline stages.
+    /// Sorted by sensor unit then group
+    pub fn get_registered_sensors(&self, _py: Python<'_>) -> PyResult<Vec<PySensorRegistration>> {
+        let json_value = self.inner.export_device_registrations_as_config_json()
+            .map_err(PyFeagiError::from)?;
+        let (sensors, _motors) = read_device_registrations(json_value)?;
+        Ok(sensors)
+    }
+
+    /// List every registered motor group, with its channels, cortical IDs and pipeline stages.
+    /// Sorted by motor unit then group
+    pub fn get_registered_motors(&self, _py: Python<'_>) -> PyResult<Vec<PyMotorRegistration>> {
+        let json_value = self.inner.export_device_registrations_as_config_json()
+            .map_err(PyFeagiError::from)?;
+        let (_sensors, motors) = read_device_registrations(json_value)?;
+        Ok(motors)
+    }
+
+    /// JSON Schema (draft-07) of the documents exported by export_capabilities_json, describing the registrations
+    /// and encoder / decoder properties of every sensor and motor unit
+    #[staticmethod]
+    pub fn capabilities_json_schema() -> PyResult<String> {
+        serde_json::to_string_pretty(&capabilities_json_schema())
+            .map_err(|e| PyFeagiError::from(FeagiDataError::SerializationError(e.to_string())))
+            .map_err(Into::into)
+    }
+
+    /// Validate a capabilities document against capabilities_json_schema, without importing it
     /// 
-    /// Parses JSON and updates pipeline stages and friendly names for already-registered devices.
-    /// Devices must be registered first using the appropriate register functions (e.g., sensor_simple_vision_register).
+    /// # Arguments
+    /// * `json_str` - JSON string in new capabilities format
+    /// * `require_all_sections` - Whether the feedbacks, sensor and motor sections must all be present, as
+    ///   needed by import_capabilities_json unless it merges the document (auto_register=False)
+    /// 
+    /// # Returns
+    /// A "<JSON path>: <problem>" message per problem found, such as
+    /// "$.input_units_and_encoder_properties.Vision[0][0].cortical_unit_index: expected integer, found string".
+    /// Empty if the document is valid
+    /// 
+    /// # Raises
+    /// * `DeserializationError` - If the string is not JSON
+    #[staticmethod]
+    #[pyo3(signature = (json_str, require_all_sections = true))]
+    pub fn validate_capabilities_json(json_str: &str, require_all_sections: bool) -> PyResult<Vec<String>> {
+        let document: serde_json::Value = serde_json::from_str(json_str)
+            .map_err(|e| PyFeagiError::from(FeagiDataError::DeserializationError(e.to_string())))?;
+        Ok(validate_capabilities_document(&document, require_all_sections))
+    }
+
+    /// Import capabilities from a JSON string, as exported by export_capabilities_json
+    /// 
+    /// By default, the document replaces all registrations: each device in it is registered (unit, group,
+    /// channel count, frame change handling, positioning, neuron resolution, misc data dimensions, image
+    /// properties) before its stages and friendly names are applied, so a saved capabilities file fully
+    /// reconstructs a ConnectorAgent. Registered devices missing from the document are removed.
+    /// 
+    /// Without auto_register, the document is merged instead: only the pipeline stages and friendly names of
+    /// devices that are already registered (with the same channel count) are updated, and registered devices
+    /// missing from the document are kept.
+    /// 
+    /// Motor callbacks are kept for the channels that still exist.
     /// 
     /// # Arguments
     /// * `json_str` - JSON string in new capabilities format
+    /// * `auto_register` - Create the registrations from the document. If false, merge it into the existing ones
+    /// 
+    /// The document is validated against capabilities_json_schema first. When merging, sections of it may be
+    /// left out.
     /// 
     /// # Raises
-    /// * `FeagiError` - If JSON is malformed or references unregistered devices
-    pub fn import_capabilities_json(&mut self, json_str: &str, py: Python<'_>) -> PyResult<()> {
+    /// * `DeserializationError` - If JSON is malformed or does not match the schema, listing the JSON path of
+    ///   every problem
+    /// * `FeagiError` - If the registrations cannot be created, or when merging, the document references
+    ///   unregistered devices. Nothing is changed on error
+    #[pyo3(signature = (json_str, auto_register = true))]
+    pub fn import_capabilities_json(&mut self, json_str: &str, py: Python<'_>, auto_register: bool) -> PyResult<()> {
+        let imported_registrations: serde_json::Value = serde_json::from_str(json_str)
+            .map_err(|e| PyFeagiError::from(FeagiDataError::DeserializationError(e.to_string())))?;
+        let schema_errors = validate_capabilities_document(&imported_registrations, auto_register);
+        if !schema_errors.is_empty() {
+            return Err(PyFeagiError::from(FeagiDataError::DeserializationError(
+                format!("Invalid capabilities document:\n{}", schema_errors.join("\n"))
+            )).into());
+        }
+        let registrations = if auto_register {
+            imported_registrations
+        } else {
+            let mut registrations = self.inner.export_device_registrations_as_config_json()
+                .map_err(PyFeagiError::from)?;
+            update_registrations_from(&mut registrations, &imported_registrations)
+                .map_err(PyFeagiError::from)?;
+            registrations
+        };
+
+        let _sensor_bytes_write = self.sensor_byte_export.begin_write().map_err(PyFeagiError::from)?;
         py.detach(|| {
-            let json_value: serde_json::Value = serde_json::from_str(json_str)
-                .map_err(|e| PyFeagiError::from(feagi_data_structures::FeagiDataError::DeserializationError(e.to_string())))?;
-            self.inner.import_device_registrations_as_config_json(json_value)
+            // Importing wipes the registrations first, so the document is tried on a scratch agent beforehand
+            ConnectorAgent::new().import_device_registrations_as_config_json(registrations.clone())
                 .map_err(PyFeagiError::from)?;
-            Ok(())
-        })
+            self.inner.import_device_registrations_as_config_json(registrations)
+                .map_err(PyFeagiError::from)?;
+            Ok::<(), PyErr>(())
+        })?;
+        self.reregister_motor_callbacks(py)
     }
 
     /// Encode all cached sensor data to bytes
@@ -970,10 +1285,13 @@ impl PyConnectorAgent {
     /// Encodes all sensor data that has been written to cache into neuron voxel format
     /// and then serializes to FeagiByteContainer. This should be called after writing
     /// sensor data and before sending to FEAGI.
-    pub fn sensors_encode_cached_sensor_data_to_bytes(&mut self) -> PyResult<()> {
+    pub fn sensors_encode_cached_sensor_data_to_bytes(&mut self, py: Python<'_>) -> PyResult<()> {
         use std::time::Instant;
 
         let mut sensor_cache = self.get_sensor_cache();
+
+        // Invalidate any exported view of the bytes before they get overwritten
+        let _sensor_bytes_write = self.sensor_byte_export.begin_write().map_err(PyFeagiError::from)?;
         
         // Get current time for burst
         let time_of_burst = Instant::now();
@@ -985,10 +1303,24 @@ impl PyConnectorAgent {
         // Encode neurons to bytes
         sensor_cache.encode_neurons_to_bytes()
             .map_err(PyFeagiError::from)?;
+
+        self.record_traffic(py, TrafficDirection::Sensory, sensor_cache.get_feagi_byte_container())?;
         
         Ok(())
     }
 
+    /// Records every sensory payload encoded by sensors_encode_cached_sensor_data_to_bytes and every motor payload
+    /// loaded by motors_load_in_bytes_and_verify to the given recorder, until it is closed. Payloads sent and
+    /// received by a PyAgentRuntime driving this agent are recorded too. None stops recording
+    #[pyo3(signature = (recorder))]
+    pub fn set_traffic_recorder(&mut self, recorder: Option<Py<PyTrafficRecorder>>) -> PyResult<()> {
+        Ok(self.traffic_recorder.set(recorder).map_err(PyFeagiError::from)?)
+    }
+
+    pub fn get_traffic_recorder(&self, py: Python<'_>) -> PyResult<Option<Py<PyTrafficRecorder>>> {
+        Ok(self.traffic_recorder.get(py).map_err(PyFeagiError::from)?)
+    }
+
     pub fn sensors_read_bytes(&mut self) -> PyResult<Vec<u8>> {
         let sensor_cache = self.get_sensor_cache();
         let byte_container = sensor_cache.get_feagi_byte_container();
@@ -996,26 +1328,33 @@ impl PyConnectorAgent {
         Ok(bytes)
     }
 
+    /// Zero copy alternative to sensors_read_bytes. Returns a read only SensorBytesView directly over the
+    /// encoded sensor bytes, usable wherever a bytes-like object is accepted. No buffers can be taken from
+    /// the view after the next encode, which raises instead while buffers taken from the view
+    /// (memoryviews, numpy arrays) are still alive.
+    pub fn sensors_read_bytes_view(&self) -> PyResult<PySensorBytesView> {
+        Ok(self.sensor_byte_export.export(self.inner.get_sensor_cache_ref()).map_err(PyFeagiError::from)?)
+    }
+
     /// Can take in a BytesArray (faster) or Bytes. Loads into rust memory and ensures the structure is sound.
-    pub fn motors_load_in_bytes_and_verify(&mut self, _py: Python<'_>, obj: &Bound<PyAny>) -> PyResult<()> {
+    pub fn motors_load_in_bytes_and_verify(&mut self, py: Python<'_>, obj: &Bound<PyAny>) -> PyResult<()> {
+        let mut motor_cache = self.get_motor_cache();
+        let byte_container = motor_cache.get_feagi_byte_container_mut();
         if let Ok(bytes) = Bound::cast::<PyByteArray>(obj) {
             let byte_data = bytes.to_vec();
-            let mut motor_cache = self.get_motor_cache();
-            let byte_container = motor_cache.get_feagi_byte_container_mut();
             byte_container.try_write_data_by_ownership_to_container_and_verify(byte_data).map_err(PyFeagiError::from)?;
-            return Ok(());
         }
         else if let Ok(bytes) = Bound::cast::<PyBytes>(obj) {
             let byte_data = bytes.extract::<&[u8]>()?;
-            let mut motor_cache = self.get_motor_cache();
-            let byte_container = motor_cache.get_feagi_byte_container_mut();
             byte_container.try_write_data_by_copy_and_verify(byte_data).map_err(PyFeagiError::from)?;
-            return Ok(());
         }
-        Err(PyFeagiError::from(FeagiDataError::BadParameters(
-            "Expected preferably a ByteArray or Bytes!".into(),
-        ))
-        .into())
+        else {
+            return Err(PyFeagiError::from(FeagiDataError::BadParameters(
+                "Expected preferably a ByteArray or Bytes!".into(),
+            ))
+            .into());
+        }
+        self.record_traffic(py, TrafficDirection::Motor, motor_cache.get_feagi_byte_container())
     }
 
     pub fn motors_decode_cached_byte_data_to_motor(&mut self) -> PyResult<()> {
@@ -1,7 +1,7 @@
 use pyo3::{pyclass, pymethods, PyResult};
 use pyo3::prelude::*;
 use feagi_sensorimotor::data_types::descriptors::*;
-use crate::{create_pyclass, __base_py_class_shared};
+use crate::{create_pyclass, __base_py_class_shared, simple_enum_pickle_support};
 use crate::feagi_connector_core::data_types::{PyImageFrame, PySegmentedImageFrame};
 use crate::py_error::PyFeagiError;
 
@@ -222,6 +222,8 @@ pub enum PyColorSpace {
     Gamma,
 }
 
+simple_enum_pickle_support!(PyColorSpace, [Linear, Gamma]);
+
 impl From<PyColorSpace> for ColorSpace {
     fn from(py_color_space: PyColorSpace) -> Self {
         match py_color_space {
@@ -254,6 +256,8 @@ pub enum PyColorChannelLayout {
     RGBA
 }
 
+simple_enum_pickle_support!(PyColorChannelLayout, [GrayScale, RG, RGB, RGBA]);
+
 impl From<PyColorChannelLayout> for ColorChannelLayout {
     fn from(py_channel_format: PyColorChannelLayout) -> Self {
         match py_channel_format {
@@ -298,6 +302,8 @@ pub enum PyMemoryOrderLayout {
     WidthsChannelsHeights,
 }
 
+simple_enum_pickle_support!(PyMemoryOrderLayout, [HeightsWidthsChannels, ChannelsHeightsWidths, WidthsHeightsChannels, HeightsChannelsWidths, ChannelsWidthsHeights, WidthsChannelsHeights]);
+
 impl From<PyMemoryOrderLayout> for MemoryOrderLayout {
     fn from(py_memory_layout: PyMemoryOrderLayout) -> Self {
         match py_memory_layout {
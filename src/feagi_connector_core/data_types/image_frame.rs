@@ -1,8 +1,12 @@
+use ndarray::ArrayView3;
 use numpy::{PyArray3, PyReadonlyArray3};
 use pyo3::prelude::*;
 use pyo3::exceptions::PyValueError;
+use feagi_data_structures::FeagiDataError;
 use feagi_sensorimotor::data_types::ImageFrame;
-use pyo3::types::PyBytes;
+use feagi_sensorimotor::data_types::descriptors::MemoryOrderLayout;
+use pyo3::types::{PyBytes, PyDict};
+use crate::py_error::PyFeagiError;
 use crate::feagi_connector_core::data_types::descriptors::*;
 use crate::{create_pyclass, __base_py_class_shared};
 
@@ -107,7 +111,22 @@ impl PyImageFrame {
         self.inner.get_color_channel_count()
     }
 
-    // NOTE: get_pixels_view, get_pixels_view_mut skipped, equivalent is copy_to_numpy_array
+    /// Returns a read only numpy array sharing the pixel buffer of this frame (no copy), in row major
+    /// (height, width, channels) order. The array keeps the frame alive, and sees every later change of its pixels.
+    /// To change the pixels, use write_from_numpy_array
+    pub fn get_pixels_view<'py>(slf: Bound<'py, Self>) -> PyResult<Bound<'py, PyArray3<u8>>> {
+        let py = slf.py();
+        let frame = slf.borrow();
+        // SAFETY: The pixel buffer is never reallocated while the frame is alive, as no method replaces it
+        // (__setstate__ restores into the existing buffer), and the frame is kept alive as the base of the array.
+        // The array is made read only before it is handed out, and numpy cannot make it writeable again as the
+        // frame does not export a writeable buffer, so the pixels are only ever written through the frame
+        let view = unsafe { PyArray3::borrow_from_array(frame.inner.get_internal_data(), slf.clone().into_any()) };
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("write", false)?;
+        view.call_method("setflags", (), Some(&kwargs))?;
+        Ok(view)
+    }
 
     #[getter]
     pub fn get_xy_resolution(&self) -> PyImageXYResolution {
@@ -128,6 +147,31 @@ impl PyImageFrame {
         Ok(Py::from(PyArray3::from_array(py, &self.inner.get_pixels_view())))
     }
 
+    /// Overwrites the pixels of this frame from the given array, without allocating a new frame. The memory order
+    /// is converted while copying, and the resolution and channel count must match this frame.
+    /// The array must not share memory with this frame (such as its get_pixels_view), copy it first instead
+    pub fn write_from_numpy_array(&mut self, input: PyReadonlyArray3<u8>, source_memory_order: PyMemoryOrderLayout) -> PyResult<()> {
+        let input = input.as_array();
+        let pixels = self.inner.get_internal_data();
+        if byte_ranges_overlap(byte_range(&input), byte_range(&pixels.view())) {
+            return Err(PyFeagiError::from(FeagiDataError::BadParameters(
+                "The array shares memory with this frame! Pass a copy of it instead".into()
+            )).into());
+        }
+
+        let input = row_major_view(input, &source_memory_order.into());
+        let pixels = self.inner.get_internal_data_mut();
+        if input.shape() != pixels.shape() {
+            return Err(PyFeagiError::from(FeagiDataError::BadParameters(format!(
+                "Expected an array of (height, width, channels) {:?} once in row major order, but got {:?}!",
+                pixels.shape(), input.shape()
+            ))).into());
+        }
+        pixels.assign(&input);
+        self.inner.skip_encoding = false;
+        Ok(())
+    }
+
     #[getter]
     pub fn skip_encoding(&self) -> bool { // Since we cannot expose the inner public property, we do this
         self.inner.skip_encoding
@@ -198,3 +242,59 @@ impl PyImageFrame {
 
     //endregion
 }
+
+/// Range of the memory spanned by the given array, whatever its strides. Empty if the array has no elements
+fn byte_range(array: &ArrayView3<'_, u8>) -> std::ops::Range<*const u8> {
+    if array.is_empty() {
+        return array.as_ptr()..array.as_ptr();
+    }
+    let (mut lowest, mut highest) = (0isize, 0isize);
+    for (&length, &stride) in array.shape().iter().zip(array.strides()) {
+        let extent = (length as isize - 1) * stride;
+        if extent < 0 { lowest += extent } else { highest += extent }
+    }
+    array.as_ptr().wrapping_offset(lowest)..array.as_ptr().wrapping_offset(highest + 1)
+}
+
+fn byte_ranges_overlap(a: std::ops::Range<*const u8>, b: std::ops::Range<*const u8>) -> bool {
+    a.start < a.end && b.start < b.end && a.start < b.end && b.start < a.end
+}
+
+/// Views the given array in row major (height, width, channels) order, the internal memory layout of ImageFrame
+fn row_major_view<'a>(input: ArrayView3<'a, u8>, source_memory_order: &MemoryOrderLayout) -> ArrayView3<'a, u8> {
+    match source_memory_order {
+        MemoryOrderLayout::HeightsWidthsChannels => input,
+        MemoryOrderLayout::ChannelsHeightsWidths => input.permuted_axes([1, 2, 0]),
+        MemoryOrderLayout::WidthsHeightsChannels => input.permuted_axes([1, 0, 2]),
+        MemoryOrderLayout::HeightsChannelsWidths => input.permuted_axes([0, 2, 1]),
+        MemoryOrderLayout::ChannelsWidthsHeights => input.permuted_axes([2, 1, 0]),
+        MemoryOrderLayout::WidthsChannelsHeights => input.permuted_axes([2, 0, 1]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{s, Array3, Axis};
+
+    #[test]
+    fn byte_range_spans_views_with_any_strides() {
+        let pixels = Array3::<u8>::zeros((4, 5, 3));
+        let full = byte_range(&pixels.view());
+        assert_eq!(full.end as usize - full.start as usize, pixels.len());
+
+        let mut flipped = pixels.view();
+        flipped.invert_axis(Axis(1));
+        assert_eq!(byte_range(&flipped), full);
+        assert_eq!(byte_range(&pixels.view().permuted_axes([2, 0, 1])), full);
+
+        let first_row = pixels.slice(s![0..1, .., ..]);
+        let last_row = pixels.slice(s![3..4, .., ..]);
+        assert!(byte_ranges_overlap(byte_range(&first_row), full.clone()));
+        assert!(!byte_ranges_overlap(byte_range(&first_row), byte_range(&last_row)));
+        assert!(!byte_ranges_overlap(byte_range(&pixels.slice(s![0..0, .., ..])), full));
+
+        let other = Array3::<u8>::zeros((4, 5, 3));
+        assert!(!byte_ranges_overlap(byte_range(&other.view()), byte_range(&pixels.view())));
+    }
+}
@@ -1,7 +1,10 @@
 use pyo3::{pyclass, pymethods, PyResult};
 use pyo3::exceptions::{PyValueError};
+use feagi_sensorimotor::data_types::ImageFrame;
 use feagi_sensorimotor::data_types::processing::ImageFrameProcessor;
-use crate::feagi_connector_core::data_types::descriptors::PyImageFrameProperties;
+use crate::feagi_connector_core::data_types::descriptors::{PyColorSpace, PyCornerPoints, PyImageFrameProperties, PyImageXYResolution};
+use crate::feagi_connector_core::data_types::PyImageFrame;
+use crate::py_error::PyFeagiError;
 
 #[pyclass(name = "ImageFrameProcessor")]
 #[derive(Clone)]
@@ -12,8 +15,6 @@ pub struct PyImageFrameProcessor {
 #[pymethods]
 impl PyImageFrameProcessor {
 
-    // TODO other methods
-
     #[new]
     pub fn new(input_image_properties: PyImageFrameProperties) -> PyResult<Self> {
         Ok(ImageFrameProcessor::new(input_image_properties.into()).into())
@@ -29,8 +30,54 @@ impl PyImageFrameProcessor {
         }
     }
 
+    //region Properties
+
+    pub fn get_input_image_properties(&self) -> PyImageFrameProperties {
+        (*self.inner.get_input_image_properties()).into()
+    }
+
+    /// Properties of the images output by this processor, given the transformations currently set
+    pub fn get_output_image_properties(&self) -> PyImageFrameProperties {
+        self.inner.get_output_image_properties().into()
+    }
+
+    pub fn verify_input_image_allowed(&self, image_frame: &PyImageFrame) -> PyResult<()> {
+        self.inner.verify_input_image_allowed(&image_frame.inner)
+            .map_err(PyFeagiError::from)?;
+        Ok(())
+    }
+
+    //endregion
+
+    //region Processing
+
+    /// Runs the given image through the processor, returning the image as FEAGI would receive it
+    pub fn process_image(&self, image_frame: &PyImageFrame) -> PyResult<PyImageFrame> {
+        self.inner.verify_input_image_allowed(&image_frame.inner)
+            .map_err(PyFeagiError::from)?;
+        let mut destination = ImageFrame::new_from_image_frame_properties(&self.inner.get_output_image_properties())
+            .map_err(PyFeagiError::from)?;
+        self.inner.process_image(&image_frame.inner, &mut destination)
+            .map_err(PyFeagiError::from)?;
+        Ok(destination.into())
+    }
+
+    //endregion
+
     //region Set Settings
 
+    pub fn set_cropping_from(&mut self, corner_points: PyCornerPoints) -> PyResult<()> {
+        self.inner.set_cropping_from(corner_points.into())
+            .map_err(PyFeagiError::from)?;
+        Ok(())
+    }
+
+    pub fn set_resizing_to(&mut self, new_xy_resolution: PyImageXYResolution) -> PyResult<()> {
+        self.inner.set_resizing_to(new_xy_resolution.into())
+            .map_err(PyFeagiError::from)?;
+        Ok(())
+    }
+
     pub fn set_brightness_offset(&mut self, brightness_offset: i32) -> PyResult<()> {
         self.inner.set_brightness_offset(brightness_offset)
             .map_err(|e| PyValueError::new_err(e.to_string()))?;
@@ -43,10 +90,55 @@ impl PyImageFrameProcessor {
         Ok(())
     }
 
+    pub fn set_color_space_to(&mut self, color_space: PyColorSpace) -> PyResult<()> {
+        self.inner.set_color_space_to(&color_space.into())
+            .map_err(PyFeagiError::from)?;
+        Ok(())
+    }
+
+    pub fn set_conversion_to_grayscale(&mut self, convert_to_grayscale: bool) -> PyResult<()> {
+        self.inner.set_conversion_to_grayscale(convert_to_grayscale)
+            .map_err(PyFeagiError::from)?;
+        Ok(())
+    }
 
     //endregion
 
+    //region Clear Settings
+
+    pub fn clear_all_transformations(&mut self) {
+        self.inner.clear_all_transformations();
+    }
+
+    pub fn clear_cropping(&mut self) {
+        self.inner.clear_cropping();
+    }
+
+    pub fn clear_resizing(&mut self) {
+        self.inner.clear_resizing();
+    }
+
+    pub fn clear_brightness_adjustment(&mut self) {
+        self.inner.clear_brightness_adjustment();
+    }
+
+    pub fn clear_contrast_adjustment(&mut self) {
+        self.inner.clear_contrast_adjustment();
+    }
+
+    pub fn clear_color_space_conversion(&mut self) {
+        self.inner.clear_color_space_conversion();
+    }
 
+    pub fn clear_grayscale_conversion(&mut self) {
+        self.inner.clear_grayscale_conversion();
+    }
+
+    //endregion
+
+    fn __repr__(&self) -> String {
+        self.inner.to_string()
+    }
 }
 
 impl From<ImageFrameProcessor> for PyImageFrameProcessor {
@@ -1,11 +1,10 @@
-use feagi_data_structures::processing::{ImageFrameSegmentator};
 use pyo3::{pyclass, pymethods, PyResult};
-use pyo3::exceptions::{PyValueError};
-use crate::feagi_connector_core::data::descriptors::{PyGazeProperties, PyImageFrameProperties, PySegmentedImageFrameProperties};
-use crate::{project_display, py_object_cast_generic, py_type_casts};
+use feagi_sensorimotor::data_types::processing::ImageFrameSegmentator;
+use crate::feagi_connector_core::data_types::descriptors::{PyImageFrameProperties, PySegmentedImageFrameProperties};
+use crate::feagi_connector_core::data_types::{PyGazeProperties, PyImageFrame, PySegmentedImageFrame};
+use crate::py_error::PyFeagiError;
 
-#[pyclass]
-#[pyo3(name = "ImageFrameSegmentator")]
+#[pyclass(name = "ImageFrameSegmentator")]
 #[derive(Clone)]
 pub struct PyImageFrameSegmentator {
     inner: ImageFrameSegmentator
@@ -14,18 +13,71 @@ pub struct PyImageFrameSegmentator {
 #[pymethods]
 impl PyImageFrameSegmentator {
 
-    // TODO other methods
-
     #[new]
     pub fn new(input_properties: PyImageFrameProperties, output_properties: PySegmentedImageFrameProperties, initial_gaze: PyGazeProperties) -> PyResult<Self> {
-        let result = ImageFrameSegmentator::new(input_properties.into(), output_properties.into(), initial_gaze.into());
-        match result {
-            Ok(inner) => Ok(PyImageFrameSegmentator { inner }),
-            Err(e) => Err(PyValueError::new_err(e.to_string()))
-        }
+        let inner = ImageFrameSegmentator::new(input_properties.into(), output_properties.into(), initial_gaze.into())
+            .map_err(PyFeagiError::from)?;
+        Ok(PyImageFrameSegmentator { inner })
+    }
+
+    //region Gaze
+
+    /// Moves the gaze used by subsequent calls to segment_image
+    pub fn update_gaze(&mut self, gaze: PyGazeProperties) -> PyResult<()> {
+        self.inner.update_gaze(&gaze.into())
+            .map_err(PyFeagiError::from)?;
+        Ok(())
+    }
+
+    #[getter]
+    pub fn gaze(&self) -> PyGazeProperties {
+        self.inner.get_used_gaze().into()
+    }
+
+    //endregion
+
+    //region Segmenting
+
+    pub fn verify_input_image(&self, image_frame: &PyImageFrame) -> PyResult<()> {
+        self.inner.verify_input_image(&image_frame.inner)
+            .map_err(PyFeagiError::from)?;
+        Ok(())
+    }
+
+    pub fn verify_output_image(&self, segmented_image_frame: &PySegmentedImageFrame) -> PyResult<()> {
+        self.inner.verify_output_image(&segmented_image_frame.inner)
+            .map_err(PyFeagiError::from)?;
+        Ok(())
+    }
+
+    /// Splits the given image into the center and 8 peripheral segments around the current gaze
+    pub fn segment_image(&mut self, image_frame: &PyImageFrame) -> PyResult<PySegmentedImageFrame> {
+        let mut target = self.inner.create_blank_segmented_image_for_use_as_write_cache();
+        self.inner.segment_image(&image_frame.inner, &mut target)
+            .map_err(PyFeagiError::from)?;
+        Ok(target.into())
     }
+
+    /// Same as segment_image, but writes into an existing segmented image frame, avoiding an allocation per call
+    pub fn segment_image_into(&mut self, image_frame: &PyImageFrame, mut target: pyo3::PyRefMut<PySegmentedImageFrame>) -> PyResult<()> {
+        self.inner.verify_output_image(&target.inner)
+            .map_err(PyFeagiError::from)?;
+        self.inner.segment_image(&image_frame.inner, &mut target.inner)
+            .map_err(PyFeagiError::from)?;
+        Ok(())
+    }
+
+    //endregion
 }
 
-py_type_casts!(PyImageFrameSegmentator, ImageFrameSegmentator);
-py_object_cast_generic!(PyImageFrameSegmentator, ImageFrameSegmentator, "Unable to retrieve ImageFrameSegmentator data from given!");
-//project_display!(PyImageFrameSegmentator);
\ No newline at end of file
+impl From<ImageFrameSegmentator> for PyImageFrameSegmentator {
+    fn from(inner: ImageFrameSegmentator) -> Self {
+        PyImageFrameSegmentator { inner }
+    }
+}
+
+impl From<PyImageFrameSegmentator> for ImageFrameSegmentator {
+    fn from(val: PyImageFrameSegmentator) -> Self {
+        val.inner
+    }
+}
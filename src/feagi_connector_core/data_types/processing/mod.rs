@@ -1,5 +1,5 @@
 mod image_frame_processor;
-//mod image_frame_segmentator;
+mod image_frame_segmentator;
 
 pub use image_frame_processor::PyImageFrameProcessor;
-//pub use image_frame_segmentator::PyImageFrameSegmentator;
+pub use image_frame_segmentator::PyImageFrameSegmentator;
@@ -1,9 +1,27 @@
+use ndarray::{s, Array3};
+use numpy::{PyArray3, PyReadonlyArray3};
 use pyo3::prelude::*;
 use pyo3::exceptions::PyValueError;
-use feagi_sensorimotor::data_types::SegmentedImageFrame;
+use feagi_data_structures::FeagiDataError;
+use feagi_sensorimotor::data_types::{ImageFrame, SegmentedImageFrame};
 use crate::feagi_connector_core::data_types::descriptors::*;
+use crate::feagi_connector_core::data_types::PyImageFrame;
+use crate::py_error::PyFeagiError;
 use crate::{create_pyclass, __base_py_class_shared};
 
+/// Segment names, in the order of SegmentedImageFrame::get_ordered_image_frame_references (and the cortical IDs)
+const SEGMENT_NAMES: [&str; 9] = [
+    "lower_left", "lower_middle", "lower_right",
+    "middle_left", "center", "middle_right",
+    "upper_left", "upper_middle", "upper_right",
+];
+
+fn segment_index(segment_name: &str) -> Result<usize, FeagiDataError> {
+    SEGMENT_NAMES.iter().position(|name| *name == segment_name).ok_or_else(|| FeagiDataError::BadParameters(format!(
+        "Unknown segment '{}'! Expected one of {}", segment_name, SEGMENT_NAMES.join(", ")
+    )))
+}
+
 create_pyclass!(PySegmentedImageFrame, SegmentedImageFrame, "SegmentedImageFrame");
 
 #[pymethods]
@@ -62,4 +80,113 @@ impl PySegmentedImageFrame {
         self.inner.get_segmented_frame_target_resolutions().into()
     }
     //endregion
+
+    //region Segment Pixel Access
+
+    /// Names of the segments, usable with the per segment methods
+    #[staticmethod]
+    pub fn segment_names() -> Vec<&'static str> {
+        SEGMENT_NAMES.to_vec()
+    }
+
+    /// Returns a copy of the given segment as an ImageFrame
+    pub fn get_segment(&self, segment_name: &str) -> PyResult<PyImageFrame> {
+        let index = segment_index(segment_name).map_err(PyFeagiError::from)?;
+        Ok(self.inner.get_ordered_image_frame_references()[index].clone().into())
+    }
+
+    /// Returns a copy of the pixels of the given segment, in row major (height, width, channels) order
+    pub fn copy_segment_to_numpy_array(&self, py: Python, segment_name: &str) -> PyResult<Py<PyArray3<u8>>> {
+        let index = segment_index(segment_name).map_err(PyFeagiError::from)?;
+        let segment = self.inner.get_ordered_image_frame_references()[index];
+        Ok(Py::from(PyArray3::from_array(py, &segment.get_pixels_view())))
+    }
+
+    /// Overwrites the pixels of the given segment. The resolution and channel count must match the segment
+    pub fn set_segment_from_numpy_array(&mut self, segment_name: &str, input: PyReadonlyArray3<u8>, source_memory_order: PyMemoryOrderLayout) -> PyResult<()> {
+        let index = segment_index(segment_name).map_err(PyFeagiError::from)?;
+        let color_space = *self.inner.get_color_space();
+        let image_frame = ImageFrame::from_array(input.as_array().to_owned(), &color_space, &source_memory_order.into())
+            .map_err(PyFeagiError::from)?;
+        self.set_segment_image_frame(index, &image_frame)
+    }
+
+    /// Overwrites the pixels of the given segment. The resolution, channel layout and color space must match the segment
+    pub fn set_segment(&mut self, segment_name: &str, image_frame: &PyImageFrame) -> PyResult<()> {
+        let index = segment_index(segment_name).map_err(PyFeagiError::from)?;
+        if image_frame.inner.get_color_space() != self.inner.get_color_space() {
+            return Err(PyFeagiError::from(FeagiDataError::BadParameters(format!(
+                "Segment '{}' expects color space {}, but got {}!",
+                segment_name, self.inner.get_color_space(), image_frame.inner.get_color_space()
+            ))).into());
+        }
+        self.set_segment_image_frame(index, &image_frame.inner)
+    }
+
+    /// Tiles all nine segments into a single image laid out as seen by the camera (upper row on top), for debugging.
+    /// Segments are centered in their cell and separated by spacing black pixels. Segments with fewer channels
+    /// than the composite are widened (grayscale is repeated across the color channels, alpha is opaque)
+    #[pyo3(signature = (spacing = 1))]
+    pub fn render_composite(&self, spacing: usize) -> PyResult<PyImageFrame> {
+        let segments = self.inner.get_ordered_image_frame_references();
+        let channel_count = segments.iter().map(|segment| segment.get_color_channel_count()).max().unwrap_or(1);
+
+        // Segments are ordered from the lower row up, while image rows go from the top down
+        let grid_position = |index: usize| (2 - index / 3, index % 3);
+        let mut row_heights = [0usize; 3];
+        let mut column_widths = [0usize; 3];
+        for (index, segment) in segments.iter().enumerate() {
+            let (row, column) = grid_position(index);
+            let shape = segment.get_internal_data().shape();
+            row_heights[row] = row_heights[row].max(shape[0]);
+            column_widths[column] = column_widths[column].max(shape[1]);
+        }
+        let offsets = |sizes: &[usize; 3]| [0, sizes[0] + spacing, sizes[0] + sizes[1] + 2 * spacing];
+        let (row_offsets, column_offsets) = (offsets(&row_heights), offsets(&column_widths));
+
+        let mut composite = Array3::<u8>::zeros((
+            row_heights.iter().sum::<usize>() + 2 * spacing,
+            column_widths.iter().sum::<usize>() + 2 * spacing,
+            channel_count,
+        ));
+        for (index, segment) in segments.iter().enumerate() {
+            let (row, column) = grid_position(index);
+            let pixels = segment.get_internal_data();
+            let (height, width, segment_channel_count) = pixels.dim();
+            let top = row_offsets[row] + (row_heights[row] - height) / 2;
+            let left = column_offsets[column] + (column_widths[column] - width) / 2;
+            let mut cell = composite.slice_mut(s![top..top + height, left..left + width, ..]);
+            for channel in 0..channel_count {
+                let mut cell_channel = cell.slice_mut(s![.., .., channel]);
+                if channel < segment_channel_count {
+                    cell_channel.assign(&pixels.slice(s![.., .., channel]));
+                } else if segment_channel_count == 1 && channel < 3 {
+                    cell_channel.assign(&pixels.slice(s![.., .., 0]));
+                } else if channel == 3 {
+                    cell_channel.fill(u8::MAX);
+                }
+            }
+        }
+
+        let image_frame = ImageFrame::from_array(composite, self.inner.get_color_space(), &ImageFrame::INTERNAL_MEMORY_LAYOUT)
+            .map_err(PyFeagiError::from)?;
+        Ok(image_frame.into())
+    }
+
+    //endregion
+}
+
+impl PySegmentedImageFrame {
+    fn set_segment_image_frame(&mut self, index: usize, image_frame: &ImageFrame) -> PyResult<()> {
+        let segment = &mut self.inner.get_mut_ordered_image_frame_references()[index];
+        if image_frame.get_internal_data().shape() != segment.get_internal_data().shape() {
+            return Err(PyFeagiError::from(FeagiDataError::BadParameters(format!(
+                "Segment '{}' expects (height, width, channels) of {:?}, but got {:?}!",
+                SEGMENT_NAMES[index], segment.get_internal_data().shape(), image_frame.get_internal_data().shape()
+            ))).into());
+        }
+        segment.get_internal_data_mut().assign(image_frame.get_internal_data());
+        segment.skip_encoding = false;
+        Ok(())
+    }
 }
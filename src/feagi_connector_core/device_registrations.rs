@@ -0,0 +1,582 @@
+use pyo3::prelude::*;
+use serde_json::{Map, Value};
+use feagi_agent::sdk::ConnectorAgent;
+use feagi_data_structures::{sensor_cortical_units, FeagiDataError};
+use feagi_data_structures::genomic::{MotorCorticalUnit, SensoryCorticalUnit};
+use feagi_data_structures::genomic::cortical_area::CorticalID;
+use feagi_data_structures::genomic::cortical_area::descriptors::{CorticalChannelCount, CorticalChannelIndex, CorticalUnitIndex};
+use feagi_data_structures::genomic::cortical_area::io_cortical_area_configuration_flag::{FrameChangeHandling, PercentageNeuronPositioning};
+use feagi_sensorimotor::configuration::jsonable::{JSONInputOutputDefinition, JSONUnitDefinition};
+use feagi_sensorimotor::caching::SensorDeviceCache;
+use feagi_sensorimotor::data_pipeline::PipelineStageProperties;
+use feagi_sensorimotor::feedbacks::{FeedBackRegistration, FeedbackRegistrationTargets};
+use feagi_sensorimotor::wrapped_io_data::WrappedIOData;
+use crate::feagi_connector_core::data_pipeline::pipeline_stage_properties::PyPipelineStageProperties;
+use crate::feagi_data_structures::genomic::{PyMotorCorticalUnit, PySensoryCorticalUnit};
+use crate::feagi_data_structures::genomic::cortical_area::{PyCorticalID, PyFrameChangeHandling, PyPercentageNeuronPositioning};
+use crate::py_error::PyFeagiError;
+
+/// A single channel of a registered device group
+#[pyclass(name = "ChannelRegistration")]
+#[derive(Debug, Clone)]
+pub struct PyChannelRegistration {
+    channel_index: u32,
+    friendly_name: Option<String>,
+    pipeline_stages: Vec<PipelineStageProperties>,
+}
+
+#[pymethods]
+impl PyChannelRegistration {
+    #[getter]
+    pub fn channel_index(&self) -> u32 {
+        self.channel_index
+    }
+
+    #[getter]
+    pub fn friendly_name(&self) -> Option<String> {
+        self.friendly_name.clone()
+    }
+
+    /// Copies of the pipeline stages processing this channel, in order
+    #[getter]
+    pub fn pipeline_stages(&self, py: Python<'_>) -> PyResult<Vec<Py<PyPipelineStageProperties>>> {
+        PyPipelineStageProperties::from_vec_box_to_vec_parent_typed(py, self.pipeline_stages.clone())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ChannelRegistration(channel_index={}, friendly_name={:?}, pipeline_stages={})",
+                self.channel_index, self.friendly_name, self.pipeline_stages.len())
+    }
+}
+
+/// Properties shared between sensor and motor registrations
+#[derive(Debug, Clone)]
+struct UnitRegistration {
+    group: u8,
+    friendly_name: Option<String>,
+    frame_change_handling: Option<FrameChangeHandling>,
+    percentage_neuron_positioning: Option<PercentageNeuronPositioning>,
+    cortical_ids: Vec<CorticalID>,
+    channels: Vec<PyChannelRegistration>,
+}
+
+impl UnitRegistration {
+    /// Reads a unit definition as exported by the device caches. The cortical IDs are resolved from the
+    /// group and configuration flags by the given function, as it depends on the unit being a sensor or a motor
+    fn from_unit_definition(
+        unit_definition: &JSONUnitDefinition,
+        get_cortical_ids: impl FnOnce(CorticalUnitIndex, Map<String, Value>) -> Result<Vec<CorticalID>, FeagiDataError>,
+    ) -> Result<Self, FeagiDataError> {
+        let unit_definition = serde_json::to_value(unit_definition)
+            .map_err(|e| FeagiDataError::SerializationError(e.to_string()))?;
+
+        let group = unit_definition.get("cortical_unit_index")
+            .and_then(Value::as_u64)
+            .and_then(|group| u8::try_from(group).ok())
+            .ok_or_else(|| FeagiDataError::DeserializationError("Unit definition is missing a valid cortical_unit_index!".into()))?;
+        let friendly_name = read_friendly_name(&unit_definition);
+        let io_configuration_flags = unit_definition.get("io_configuration_flags")
+            .and_then(Value::as_object)
+            .cloned()
+            .unwrap_or_default();
+        let frame_change_handling: Option<FrameChangeHandling> = io_configuration_flags.get("frame_change_handling")
+            .map(|flag| serde_json::from_value(flag.clone()))
+            .transpose()
+            .map_err(|e| FeagiDataError::DeserializationError(format!("Invalid frame_change_handling: {}", e)))?;
+        let percentage_neuron_positioning: Option<PercentageNeuronPositioning> = io_configuration_flags.get("percentage_neuron_positioning")
+            .map(|flag| serde_json::from_value(flag.clone()))
+            .transpose()
+            .map_err(|e| FeagiDataError::DeserializationError(format!("Invalid percentage_neuron_positioning: {}", e)))?;
+
+        let device_groupings = unit_definition.get("device_grouping")
+            .and_then(Value::as_array)
+            .ok_or_else(|| FeagiDataError::DeserializationError("Unit definition is missing device_grouping!".into()))?;
+        let mut channels = Vec::with_capacity(device_groupings.len());
+        for (position, device_grouping) in device_groupings.iter().enumerate() {
+            let channel_index = device_grouping.get("channel_index_override")
+                .and_then(Value::as_u64)
+                .map_or(position as u32, |channel_index| channel_index as u32);
+            let pipeline_stages = match device_grouping.get("pipeline_stages") {
+                Some(stages) => serde_json::from_value(stages.clone())
+                    .map_err(|e| FeagiDataError::DeserializationError(e.to_string()))?,
+                None => Vec::new(),
+            };
+            channels.push(PyChannelRegistration {
+                channel_index,
+                friendly_name: read_friendly_name(device_grouping),
+                pipeline_stages,
+            });
+        }
+        channels.sort_by_key(|channel| channel.channel_index);
+
+        let cortical_ids = get_cortical_ids(group.into(), io_configuration_flags)?;
+        Ok(UnitRegistration {
+            group,
+            friendly_name,
+            frame_change_handling,
+            percentage_neuron_positioning,
+            cortical_ids,
+            channels,
+        })
+    }
+}
+
+fn read_friendly_name(value: &Value) -> Option<String> {
+    value.get("friendly_name").and_then(Value::as_str).map(str::to_string)
+}
+
+/// Generates the Python getters shared by the sensor and motor registrations
+macro_rules! unit_registration_getters {
+    ($py_wrapped_name:ident, $py_unit_type:ty, $py_name:expr) => {
+        #[pymethods]
+        impl $py_wrapped_name {
+            #[getter]
+            pub fn unit(&self) -> $py_unit_type {
+                self.unit.into()
+            }
+
+            #[getter]
+            pub fn group(&self) -> u8 {
+                self.registration.group
+            }
+
+            #[getter]
+            pub fn friendly_name(&self) -> Option<String> {
+                self.registration.friendly_name.clone()
+            }
+
+            #[getter]
+            pub fn channel_count(&self) -> u32 {
+                self.registration.channels.len() as u32
+            }
+
+            /// None for units without frame change handling (such as booleans)
+            #[getter]
+            pub fn frame_change_handling(&self) -> Option<PyFrameChangeHandling> {
+                self.registration.frame_change_handling.map(Into::into)
+            }
+
+            /// None for units not encoded as percentages
+            #[getter]
+            pub fn percentage_neuron_positioning(&self) -> Option<PyPercentageNeuronPositioning> {
+                self.registration.percentage_neuron_positioning.map(Into::into)
+            }
+
+            #[getter]
+            pub fn cortical_ids(&self) -> Vec<PyCorticalID> {
+                self.registration.cortical_ids.iter().cloned().map(Into::into).collect()
+            }
+
+            #[getter]
+            pub fn channels(&self) -> Vec<PyChannelRegistration> {
+                self.registration.channels.clone()
+            }
+
+            fn __repr__(&self) -> String {
+                format!(concat!($py_name, "(unit={:?}, group={}, channel_count={})"),
+                        self.unit, self.registration.group, self.registration.channels.len())
+            }
+        }
+    };
+}
+
+/// A group of sensors registered on a ConnectorAgent
+#[pyclass(name = "SensorRegistration")]
+#[derive(Debug, Clone)]
+pub struct PySensorRegistration {
+    unit: SensoryCorticalUnit,
+    registration: UnitRegistration,
+}
+
+unit_registration_getters!(PySensorRegistration, PySensoryCorticalUnit, "SensorRegistration");
+
+/// A group of motors registered on a ConnectorAgent
+#[pyclass(name = "MotorRegistration")]
+#[derive(Debug, Clone)]
+pub struct PyMotorRegistration {
+    unit: MotorCorticalUnit,
+    registration: UnitRegistration,
+}
+
+unit_registration_getters!(PyMotorRegistration, PyMotorCorticalUnit, "MotorRegistration");
+
+/// Reads the device registrations exported by a ConnectorAgent. Registrations are sorted by unit then group
+pub(crate) fn read_device_registrations(exported_registrations: Value) -> PyResult<(Vec<PySensorRegistration>, Vec<PyMotorRegistration>)> {
+    let definition: JSONInputOutputDefinition = serde_json::from_value(exported_registrations)
+        .map_err(|e| PyFeagiError::from(FeagiDataError::DeserializationError(e.to_string())))?;
+
+    let mut sensors = Vec::new();
+    for (unit, unit_definitions) in definition.get_input_units_and_encoder_properties() {
+        for (unit_definition, _encoder_properties) in unit_definitions {
+            let registration = UnitRegistration::from_unit_definition(unit_definition, |group, flags| {
+                unit.get_cortical_id_vector_from_index_and_serde_io_configuration_flags(group, flags)
+            }).map_err(PyFeagiError::from)?;
+            sensors.push(PySensorRegistration { unit: *unit, registration });
+        }
+    }
+    sensors.sort_by_key(|sensor| (sensor.unit.get_snake_case_name(), sensor.registration.group));
+
+    let mut motors = Vec::new();
+    for (unit, unit_definitions) in definition.get_output_units_and_decoder_properties() {
+        for (unit_definition, _decoder_properties) in unit_definitions {
+            let registration = UnitRegistration::from_unit_definition(unit_definition, |group, flags| {
+                unit.get_cortical_id_vector_from_index_and_serde_io_configuration_flags(group, flags)
+            }).map_err(PyFeagiError::from)?;
+            motors.push(PyMotorRegistration { unit: *unit, registration });
+        }
+    }
+    motors.sort_by_key(|motor| (motor.unit.get_snake_case_name(), motor.registration.group));
+
+    Ok((sensors, motors))
+}
+
+/// Removes (number_channels of None) or resizes a registered group in device registrations exported by a
+/// ConnectorAgent. Channels added by a resize have no pipeline stages, as after a fresh registration
+pub(crate) fn resize_group_in_registrations(
+    exported_registrations: &mut Value,
+    section: &str,
+    unit_key: &str,
+    group: u8,
+    number_channels: Option<CorticalChannelCount>,
+) -> Result<(), FeagiDataError> {
+    let not_registered = || FeagiDataError::BadParameters(format!("{} of group {} is not registered!", unit_key, group));
+
+    let units = exported_registrations.get_mut(section)
+        .and_then(Value::as_object_mut)
+        .ok_or_else(|| FeagiDataError::DeserializationError(format!("Device registrations are missing {}!", section)))?;
+    let unit_definitions = units.get_mut(unit_key)
+        .and_then(Value::as_array_mut)
+        .ok_or_else(not_registered)?;
+    let position = unit_definitions.iter()
+        .position(|unit_and_coder| {
+            unit_and_coder.get(0)
+                .and_then(|unit_definition| unit_definition.get("cortical_unit_index"))
+                .and_then(Value::as_u64) == Some(group as u64)
+        })
+        .ok_or_else(not_registered)?;
+
+    let Some(number_channels) = number_channels else {
+        unit_definitions.remove(position);
+        if unit_definitions.is_empty() {
+            units.remove(unit_key);
+        }
+        return Ok(());
+    };
+
+    let number_channels = u32::from(number_channels) as usize;
+    let device_groupings = unit_definitions[position].get_mut(0)
+        .and_then(|unit_definition| unit_definition.get_mut("device_grouping"))
+        .and_then(Value::as_array_mut)
+        .ok_or_else(|| FeagiDataError::DeserializationError("Unit definition is missing device_grouping!".into()))?;
+    device_groupings.truncate(number_channels);
+    while device_groupings.len() < number_channels {
+        device_groupings.push(serde_json::json!({
+            "friendly_name": null,
+            "device_properties": {},
+            "channel_index_override": null,
+            "pipeline_stages": [],
+        }));
+    }
+    Ok(())
+}
+
+/// Removes (number_channels of None) or resizes a registered group of a ConnectorAgent. The device caches cannot
+/// drop a single group, so all registrations are imported again, without the feedbacks targeting channels that no
+/// longer exist. The cached values of sensor channels without pipeline stages outside the group are then written
+/// back (and so encoded again on the next burst). Other cached values are reset, as the input of a pipeline can't
+/// be read back
+pub(crate) fn rebuild_group(
+    agent: &mut ConnectorAgent,
+    section: &str,
+    unit_key: &str,
+    group: u8,
+    number_channels: Option<CorticalChannelCount>,
+) -> Result<(), FeagiDataError> {
+    let mut registrations = agent.export_device_registrations_as_config_json()?;
+    let rebuilt_sensor_group = (section == "input_units_and_encoder_properties").then_some((unit_key, group));
+    let kept_sensor_channels = stageless_sensor_channels(&registrations, rebuilt_sensor_group)?;
+    resize_group_in_registrations(&mut registrations, section, unit_key, group, number_channels)?;
+    prune_dangling_feedbacks(&mut registrations)?;
+
+    let definition: JSONInputOutputDefinition = serde_json::from_value(registrations.clone())
+        .map_err(|e| FeagiDataError::DeserializationError(e.to_string()))?;
+    definition.verify_valid_structure()?;
+
+    let kept_sensor_values = {
+        let mut sensor_cache = agent.get_sensor_cache();
+        kept_sensor_channels.into_iter()
+            .map(|(unit, group, channel)| Ok((unit, group, channel, read_sensor_cache_value(&mut sensor_cache, unit, group, channel)?)))
+            .collect::<Result<Vec<_>, FeagiDataError>>()?
+    };
+    agent.import_device_registrations_as_config_json(registrations)?;
+
+    let mut sensor_cache = agent.get_sensor_cache();
+    for (unit, group, channel, value) in kept_sensor_values {
+        write_sensor_cache_value(&mut sensor_cache, unit, group, channel, value)?;
+    }
+    Ok(())
+}
+
+/// Lists the sensor channels without pipeline stages in device registrations exported by a ConnectorAgent,
+/// skipping the given unit key and group
+fn stageless_sensor_channels(
+    exported_registrations: &Value,
+    skipped_group: Option<(&str, u8)>,
+) -> Result<Vec<(SensoryCorticalUnit, CorticalUnitIndex, CorticalChannelIndex)>, FeagiDataError> {
+    let mut channels = Vec::new();
+    let Some(units) = exported_registrations.get("input_units_and_encoder_properties").and_then(Value::as_object) else {
+        return Ok(channels);
+    };
+    for (unit_key, unit_definitions) in units {
+        let unit: SensoryCorticalUnit = serde_json::from_value(Value::String(unit_key.clone()))
+            .map_err(|e| FeagiDataError::DeserializationError(e.to_string()))?;
+        for unit_definition in unit_definitions.as_array().into_iter().flatten().filter_map(|definition| definition.get(0)) {
+            let Some(group) = unit_definition.get("cortical_unit_index").and_then(Value::as_u64).and_then(|group| u8::try_from(group).ok()) else {
+                continue;
+            };
+            if skipped_group == Some((unit_key.as_str(), group)) {
+                continue;
+            }
+            let device_groupings = unit_definition.get("device_grouping").and_then(Value::as_array).into_iter().flatten();
+            for (position, device_grouping) in device_groupings.enumerate() {
+                let has_stages = device_grouping.get("pipeline_stages")
+                    .and_then(Value::as_array)
+                    .is_some_and(|stages| !stages.is_empty());
+                if !has_stages {
+                    channels.push((unit, group.into(), (position as u32).into()));
+                }
+            }
+        }
+    }
+    Ok(channels)
+}
+
+/// Removes the feedbacks of device registrations exported by a ConnectorAgent whose sensor or motor channel is
+/// no longer registered, as importing them would fail
+fn prune_dangling_feedbacks(exported_registrations: &mut Value) -> Result<(), FeagiDataError> {
+    let Some(feedbacks) = exported_registrations.get("feedbacks")
+        .and_then(|feedbacks| feedbacks.get("registered_feedbacks"))
+        .and_then(Value::as_array)
+        .cloned() else {
+        return Ok(());
+    };
+
+    let mut kept_feedbacks = Vec::with_capacity(feedbacks.len());
+    for feedback in feedbacks {
+        let (targets, registration): (FeedbackRegistrationTargets, FeedBackRegistration) = serde_json::from_value(feedback.clone())
+            .map_err(|e| FeagiDataError::DeserializationError(format!("Invalid feedback registration: {}", e)))?;
+        let (sensor_unit, motor_unit) = match registration {
+            FeedBackRegistration::SegmentedVisionWithGaze {} => (SensoryCorticalUnit::SegmentedVision, MotorCorticalUnit::Gaze),
+            FeedBackRegistration::SegmentedVisionWithImageFiltering {} => (SensoryCorticalUnit::SegmentedVision, MotorCorticalUnit::DynamicImageProcessing),
+            FeedBackRegistration::VisionWithImageFiltering {} => (SensoryCorticalUnit::Vision, MotorCorticalUnit::DynamicImageProcessing),
+        };
+        let sensor_key = serde_json::to_value(sensor_unit).map_err(|e| FeagiDataError::SerializationError(e.to_string()))?;
+        let motor_key = serde_json::to_value(motor_unit).map_err(|e| FeagiDataError::SerializationError(e.to_string()))?;
+
+        let sensor_channels = registered_channel_count(exported_registrations, "input_units_and_encoder_properties", &sensor_key, *targets.get_sensor_unit_index());
+        let motor_channels = registered_channel_count(exported_registrations, "output_units_and_decoder_properties", &motor_key, *targets.get_motor_unit_index());
+        if (*targets.get_sensor_channel_index() as usize) < sensor_channels && (*targets.get_motor_channel_index() as usize) < motor_channels {
+            kept_feedbacks.push(feedback);
+        }
+    }
+
+    exported_registrations["feedbacks"]["registered_feedbacks"] = Value::Array(kept_feedbacks);
+    Ok(())
+}
+
+/// The number of channels of a group in device registrations exported by a ConnectorAgent, 0 if not registered
+fn registered_channel_count(exported_registrations: &Value, section: &str, unit_key: &Value, group: u8) -> usize {
+    exported_registrations.get(section)
+        .and_then(|units| units.get(unit_key.as_str()?))
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|definition| definition.get(0))
+        .find(|unit_definition| unit_definition.get("cortical_unit_index").and_then(Value::as_u64) == Some(group as u64))
+        .and_then(|unit_definition| unit_definition.get("device_grouping"))
+        .and_then(Value::as_array)
+        .map_or(0, Vec::len)
+}
+
+/// Generates reading and writing back the cached value of a sensor channel of any unit
+macro_rules! sensor_cache_value_access {
+    (
+        SensoryCorticalUnit {
+            $(
+                $(#[doc = $doc:expr])?
+                $cortical_type_key_name:ident => { $($unit_properties:tt)* }
+            ),* $(,)?
+        }
+    ) => {
+        ::paste::paste! {
+            fn read_sensor_cache_value(
+                sensor_cache: &mut SensorDeviceCache,
+                unit: SensoryCorticalUnit,
+                group: CorticalUnitIndex,
+                channel: CorticalChannelIndex,
+            ) -> Result<WrappedIOData, FeagiDataError> {
+                match unit {
+                    $(
+                        SensoryCorticalUnit::$cortical_type_key_name => sensor_cache
+                            .[<$cortical_type_key_name:snake _read_postprocessed_cache_value>](group, channel)
+                            .map(WrappedIOData::from),
+                    )*
+                }
+            }
+
+            fn write_sensor_cache_value(
+                sensor_cache: &mut SensorDeviceCache,
+                unit: SensoryCorticalUnit,
+                group: CorticalUnitIndex,
+                channel: CorticalChannelIndex,
+                value: WrappedIOData,
+            ) -> Result<(), FeagiDataError> {
+                match unit {
+                    $(
+                        SensoryCorticalUnit::$cortical_type_key_name => sensor_cache
+                            .[<$cortical_type_key_name:snake _write>](group, channel, value),
+                    )*
+                }
+            }
+        }
+    };
+}
+
+sensor_cortical_units!(sensor_cache_value_access);
+
+/// Copies the friendly names and channels (pipeline stages, channel friendly names and properties) of every group
+/// in an imported document into the current registrations exported by a ConnectorAgent. Each group must already
+/// be registered with the same channel count. Registration parameters and groups missing from the document are kept
+pub(crate) fn update_registrations_from(current_registrations: &mut Value, imported_registrations: &Value) -> Result<(), FeagiDataError> {
+    for section in ["input_units_and_encoder_properties", "output_units_and_decoder_properties"] {
+        let Some(imported_units) = imported_registrations.get(section) else {
+            continue;
+        };
+        let imported_units = imported_units.as_object()
+            .ok_or_else(|| FeagiDataError::DeserializationError(format!("{} must be an object!", section)))?;
+
+        for (unit_key, imported_definitions) in imported_units {
+            let imported_definitions = imported_definitions.as_array()
+                .ok_or_else(|| FeagiDataError::DeserializationError(format!("{} of {} must be an array!", unit_key, section)))?;
+            for imported_definition in imported_definitions {
+                let imported_unit_definition = imported_definition.get(0)
+                    .ok_or_else(|| FeagiDataError::DeserializationError(format!("Registration of {} is missing its unit definition!", unit_key)))?;
+                let group = imported_unit_definition.get("cortical_unit_index")
+                    .and_then(Value::as_u64)
+                    .ok_or_else(|| FeagiDataError::DeserializationError(format!("Registration of {} is missing a valid cortical_unit_index!", unit_key)))?;
+                let imported_channels = imported_unit_definition.get("device_grouping")
+                    .and_then(Value::as_array)
+                    .ok_or_else(|| FeagiDataError::DeserializationError(format!("{} of group {} is missing device_grouping!", unit_key, group)))?;
+
+                let current_unit_definition = current_registrations.get_mut(section)
+                    .and_then(|units| units.get_mut(unit_key))
+                    .and_then(Value::as_array_mut)
+                    .and_then(|definitions| definitions.iter_mut().find(|definition| {
+                        definition.get(0)
+                            .and_then(|unit_definition| unit_definition.get("cortical_unit_index"))
+                            .and_then(Value::as_u64) == Some(group)
+                    }))
+                    .and_then(|definition| definition.get_mut(0))
+                    .and_then(Value::as_object_mut)
+                    .ok_or_else(|| FeagiDataError::BadParameters(format!(
+                        "{} of group {} is not registered! Register it first, or import with auto_register=True", unit_key, group
+                    )))?;
+                let current_channel_count = current_unit_definition.get("device_grouping")
+                    .and_then(Value::as_array)
+                    .map_or(0, Vec::len);
+                if current_channel_count != imported_channels.len() {
+                    return Err(FeagiDataError::BadParameters(format!(
+                        "{} of group {} is registered with {} channels, but the document has {}!",
+                        unit_key, group, current_channel_count, imported_channels.len()
+                    )));
+                }
+
+                current_unit_definition.insert("device_grouping".to_string(), Value::Array(imported_channels.clone()));
+                current_unit_definition.insert(
+                    "friendly_name".to_string(),
+                    imported_unit_definition.get("friendly_name").cloned().unwrap_or(Value::Null),
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use feagi_data_structures::genomic::cortical_area::descriptors::NeuronDepth;
+    use feagi_sensorimotor::data_types::Percentage;
+    use serde_json::json;
+
+    fn unit_key(unit: impl serde::Serialize) -> String {
+        serde_json::to_value(unit).unwrap().as_str().unwrap().to_string()
+    }
+
+    fn registered_analog_gpio_agent() -> ConnectorAgent {
+        let agent = ConnectorAgent::new();
+        {
+            let mut sensor_cache = agent.get_sensor_cache();
+            for group in 0..2u8 {
+                sensor_cache.analog_g_p_i_o_register(
+                    group.into(),
+                    CorticalChannelCount::new(2).unwrap(),
+                    FrameChangeHandling::Absolute,
+                    NeuronDepth::new(10).unwrap(),
+                    PercentageNeuronPositioning::Linear,
+                ).unwrap();
+            }
+            sensor_cache.analog_g_p_i_o_write(1.into(), 1.into(), Percentage::new_from_0_1(0.25).unwrap().into()).unwrap();
+        }
+        agent
+    }
+
+    fn cached_analog_gpio_value(agent: &ConnectorAgent, group: u8, channel: u32) -> Result<Percentage, FeagiDataError> {
+        agent.get_sensor_cache().analog_g_p_i_o_read_postprocessed_cache_value(group.into(), channel.into())
+    }
+
+    #[test]
+    fn rebuild_group_keeps_cached_values_of_other_groups() {
+        let mut agent = registered_analog_gpio_agent();
+        let analog_gpio = unit_key(SensoryCorticalUnit::AnalogGPIO);
+
+        rebuild_group(&mut agent, "input_units_and_encoder_properties", &analog_gpio, 0, Some(CorticalChannelCount::new(3).unwrap())).unwrap();
+        assert_eq!(cached_analog_gpio_value(&agent, 1, 1).unwrap(), Percentage::new_from_0_1(0.25).unwrap());
+        assert!(cached_analog_gpio_value(&agent, 0, 2).is_ok());
+
+        rebuild_group(&mut agent, "input_units_and_encoder_properties", &analog_gpio, 0, None).unwrap();
+        assert_eq!(cached_analog_gpio_value(&agent, 1, 1).unwrap(), Percentage::new_from_0_1(0.25).unwrap());
+        assert!(cached_analog_gpio_value(&agent, 0, 0).is_err());
+    }
+
+    #[test]
+    fn rebuild_group_rejects_unregistered_group() {
+        let mut agent = registered_analog_gpio_agent();
+        let analog_gpio = unit_key(SensoryCorticalUnit::AnalogGPIO);
+
+        assert!(rebuild_group(&mut agent, "input_units_and_encoder_properties", &analog_gpio, 5, None).is_err());
+        assert_eq!(cached_analog_gpio_value(&agent, 1, 1).unwrap(), Percentage::new_from_0_1(0.25).unwrap());
+    }
+
+    #[test]
+    fn prune_dangling_feedbacks_drops_feedbacks_of_missing_channels() {
+        let feedback = |sensor_group: u8, sensor_channel: u32, motor_group: u8, motor_channel: u32| {
+            let targets = FeedbackRegistrationTargets::new(sensor_group.into(), sensor_channel.into(), motor_group.into(), motor_channel.into());
+            serde_json::to_value((targets, FeedBackRegistration::SegmentedVisionWithGaze {})).unwrap()
+        };
+        let mut registrations = json!({
+            "input_units_and_encoder_properties": {
+                unit_key(SensoryCorticalUnit::SegmentedVision): [[{"cortical_unit_index": 0, "device_grouping": [{}]}, null]],
+            },
+            "output_units_and_decoder_properties": {
+                unit_key(MotorCorticalUnit::Gaze): [[{"cortical_unit_index": 0, "device_grouping": [{}, {}]}, null]],
+            },
+            "feedbacks": {
+                "registered_feedbacks": [feedback(0, 0, 0, 1), feedback(0, 1, 0, 0), feedback(1, 0, 0, 0), feedback(0, 0, 0, 2)],
+            },
+        });
+
+        prune_dangling_feedbacks(&mut registrations).unwrap();
+        assert_eq!(registrations["feedbacks"]["registered_feedbacks"], json!([feedback(0, 0, 0, 1)]));
+    }
+}
@@ -1,6 +1,16 @@
 pub mod data_pipeline;
 pub mod data_types;
 pub mod wrapped_io_data;
+mod capabilities_schema;
 mod connector_agent;
+mod device_registrations;
+mod sensor_byte_export;
+mod sensor_channel_arrays;
+mod traffic_recording;
 
 pub use connector_agent::{PyConnectorAgent, init_rust_logging};
+pub use device_registrations::{PyChannelRegistration, PyMotorRegistration, PySensorRegistration};
+pub use sensor_byte_export::PySensorBytesView;
+pub(crate) use sensor_byte_export::SensorByteExport;
+pub use traffic_recording::{PyRecordedMessage, PyTrafficRecorder, PyTrafficReplayer};
+pub(crate) use traffic_recording::{TrafficDirection, TrafficRecorderSlot};
@@ -0,0 +1,202 @@
+use std::ffi::{c_int, CStr};
+use std::sync::{Arc, Mutex, MutexGuard};
+use pyo3::prelude::*;
+use pyo3::exceptions::PyBufferError;
+use pyo3::ffi;
+use feagi_data_structures::FeagiDataError;
+use feagi_sensorimotor::caching::SensorDeviceCache;
+use crate::py_error::PyFeagiError;
+
+const BYTE_FORMAT: &CStr = c"B";
+
+/// Bookkeeping of the buffers exported over a sensor cache's byte container
+#[derive(Debug, Default)]
+struct ExportState {
+    /// Buffers currently held by Python (memoryviews, numpy arrays, ...). The byte container must not be
+    /// written to while any is alive
+    exported_buffers: usize,
+    /// Bumped on every write, so views taken before it can no longer export buffers
+    generation: u64,
+    /// Whether the byte container is being written to right now
+    writing: bool,
+}
+
+/// Tracks the buffers exported over a sensor cache's byte container through SensorBytesView.
+/// Anything that writes to the container (encoding, rebuilding or importing registrations) must hold a
+/// SensorBytesWrite while doing so, which is refused while buffers are exported and invalidates every
+/// view taken before it.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SensorByteExport {
+    state: Arc<Mutex<ExportState>>,
+}
+
+impl SensorByteExport {
+    /// Creates a view over the current bytes of the sensor cache, valid until the next write
+    pub(crate) fn export(&self, sensor_cache: Arc<Mutex<SensorDeviceCache>>) -> Result<PySensorBytesView, FeagiDataError> {
+        let generation = self.lock()?.generation;
+        Ok(PySensorBytesView {
+            export: self.clone(),
+            sensor_cache,
+            generation,
+        })
+    }
+
+    /// Starts writing to the byte container, until the returned guard is dropped.
+    /// Fails if Python still holds buffers exported from a view (such as memoryviews or numpy arrays)
+    pub(crate) fn begin_write(&self) -> Result<SensorBytesWrite, FeagiDataError> {
+        let mut state = self.lock()?;
+        if state.exported_buffers > 0 {
+            return Err(FeagiDataError::BadParameters(format!(
+                "Sensor bytes are still exported to {} buffer(s)! Release any buffers taken from the sensor bytes view before encoding again",
+                state.exported_buffers
+            )));
+        }
+        state.writing = true;
+        state.generation += 1;
+        Ok(SensorBytesWrite { export: self.clone() })
+    }
+
+    /// Number of buffers Python currently holds. Can be checked without the GIL
+    pub(crate) fn exported_buffers(&self) -> Result<usize, FeagiDataError> {
+        Ok(self.lock()?.exported_buffers)
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, ExportState>, FeagiDataError> {
+        self.state.lock().map_err(|e| FeagiDataError::InternalError(format!("Lock poisoned: {}", e)))
+    }
+}
+
+/// Write access to a sensor cache's byte container, see SensorByteExport::begin_write
+#[derive(Debug)]
+pub(crate) struct SensorBytesWrite {
+    export: SensorByteExport,
+}
+
+impl Drop for SensorBytesWrite {
+    fn drop(&mut self) {
+        // A poisoned lock fails every later export and write anyway
+        if let Ok(mut state) = self.export.lock() {
+            state.writing = false;
+        }
+    }
+}
+
+/// Read only, zero copy view of the encoded sensor bytes, supporting the buffer protocol
+/// (memoryview(view), numpy.frombuffer(view), socket.send(view), ...).
+///
+/// Buffers can be taken from the view until the sensor bytes are encoded again. While any buffer
+/// taken from it is alive, encoding (and rebuilding or importing registrations) raises instead.
+#[pyclass(name = "SensorBytesView", frozen)]
+#[derive(Debug)]
+pub struct PySensorBytesView {
+    export: SensorByteExport,
+    /// Keeps the memory behind exported buffers alive, even if the ConnectorAgent is dropped
+    sensor_cache: Arc<Mutex<SensorDeviceCache>>,
+    generation: u64,
+}
+
+#[pymethods]
+impl PySensorBytesView {
+    /// Whether buffers can still be taken from the view, as the sensor bytes were not encoded again since
+    #[getter]
+    fn is_valid(&self) -> PyResult<bool> {
+        let state = self.export.lock().map_err(PyFeagiError::from)?;
+        Ok(state.generation == self.generation && !state.writing)
+    }
+
+    /// Number of buffers currently taken from views of the same agent
+    #[getter]
+    fn exported_buffers(&self) -> PyResult<usize> {
+        Ok(self.export.exported_buffers().map_err(PyFeagiError::from)?)
+    }
+
+    fn __len__(&self) -> PyResult<usize> {
+        Ok(self.lock_sensor_cache()?.get_feagi_byte_container().get_byte_ref().len())
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("SensorBytesView(len={}, is_valid={})", self.__len__()?, self.is_valid()?))
+    }
+
+    /// # Safety
+    /// Called by Python with a valid Py_buffer to fill
+    unsafe fn __getbuffer__(slf: Bound<'_, Self>, view: *mut ffi::Py_buffer, flags: c_int) -> PyResult<()> {
+        if view.is_null() {
+            return Err(PyBufferError::new_err("Buffer view is null"));
+        }
+        if flags & ffi::PyBUF_WRITABLE == ffi::PyBUF_WRITABLE {
+            return Err(PyBufferError::new_err("Sensor bytes are read only"));
+        }
+
+        let this = slf.get();
+        {
+            let mut state = this.export.lock().map_err(PyFeagiError::from)?;
+            if state.generation != this.generation || state.writing {
+                return Err(PyBufferError::new_err(
+                    "Sensor bytes were encoded again since this view was taken. Take a new view with sensors_read_bytes_view"
+                ));
+            }
+            // Counted before reading the memory location, so no write can start meanwhile
+            state.exported_buffers += 1;
+        }
+        let (buf, len) = match this.lock_sensor_cache() {
+            Ok(sensor_cache) => {
+                let bytes = sensor_cache.get_feagi_byte_container().get_byte_ref();
+                (bytes.as_ptr(), bytes.len())
+            }
+            Err(e) => {
+                this.release_exported_buffer();
+                return Err(e);
+            }
+        };
+
+        // SAFETY: view is a valid Py_buffer given by Python. The memory stays valid and unchanged until
+        // __releasebuffer__, as the view owns a reference to the sensor cache and writes are refused while
+        // the buffer is counted
+        unsafe {
+            (*view).obj = slf.into_any().into_ptr();
+            (*view).buf = buf as *mut std::ffi::c_void;
+            (*view).len = len as ffi::Py_ssize_t;
+            (*view).readonly = 1;
+            (*view).itemsize = 1;
+            (*view).format = if flags & ffi::PyBUF_FORMAT == ffi::PyBUF_FORMAT {
+                BYTE_FORMAT.as_ptr() as *mut _
+            } else {
+                std::ptr::null_mut()
+            };
+            (*view).ndim = 1;
+            (*view).shape = if flags & ffi::PyBUF_ND == ffi::PyBUF_ND {
+                &mut (*view).len
+            } else {
+                std::ptr::null_mut()
+            };
+            (*view).strides = if flags & ffi::PyBUF_STRIDES == ffi::PyBUF_STRIDES {
+                &mut (*view).itemsize
+            } else {
+                std::ptr::null_mut()
+            };
+            (*view).suboffsets = std::ptr::null_mut();
+            (*view).internal = std::ptr::null_mut();
+        }
+        Ok(())
+    }
+
+    /// # Safety
+    /// Called by Python once per buffer filled by __getbuffer__
+    unsafe fn __releasebuffer__(&self, _view: *mut ffi::Py_buffer) {
+        self.release_exported_buffer();
+    }
+}
+
+impl PySensorBytesView {
+    fn lock_sensor_cache(&self) -> PyResult<MutexGuard<'_, SensorDeviceCache>> {
+        self.sensor_cache.lock()
+            .map_err(|e| PyFeagiError::from(FeagiDataError::InternalError(format!("Lock poisoned: {}", e))).into())
+    }
+
+    fn release_exported_buffer(&self) {
+        if let Ok(mut state) = self.export.lock() {
+            state.exported_buffers = state.exported_buffers.saturating_sub(1);
+        }
+    }
+}
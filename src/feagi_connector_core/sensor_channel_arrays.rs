@@ -0,0 +1,74 @@
+use ndarray::{ArrayViewD, Axis};
+use feagi_data_structures::FeagiDataError;
+use feagi_sensorimotor::data_types::*;
+use feagi_sensorimotor::wrapped_io_data::WrappedIOData;
+
+/// Sensor data types that can be built from a row of floats, one row per channel
+pub(crate) trait SensorChannelRow: Into<WrappedIOData> {
+    /// Number of floats making up a single channel
+    const DIMENSIONS: usize;
+
+    /// Builds the data of a single channel, validating the range of every value
+    fn try_from_channel_row(row: &[f32]) -> Result<Self, FeagiDataError>;
+}
+
+impl SensorChannelRow for Percentage {
+    const DIMENSIONS: usize = 1;
+
+    fn try_from_channel_row(row: &[f32]) -> Result<Self, FeagiDataError> {
+        Percentage::new_from_0_1(row[0])
+    }
+}
+
+impl SensorChannelRow for Percentage3D {
+    const DIMENSIONS: usize = 3;
+
+    fn try_from_channel_row(row: &[f32]) -> Result<Self, FeagiDataError> {
+        Ok(Percentage3D::new(
+            Percentage::new_from_0_1(row[0])?,
+            Percentage::new_from_0_1(row[1])?,
+            Percentage::new_from_0_1(row[2])?,
+        ))
+    }
+}
+
+impl SensorChannelRow for SignedPercentage4D {
+    const DIMENSIONS: usize = 4;
+
+    fn try_from_channel_row(row: &[f32]) -> Result<Self, FeagiDataError> {
+        Ok(SignedPercentage4D::new(
+            SignedPercentage::new_from_m1_1(row[0])?,
+            SignedPercentage::new_from_m1_1(row[1])?,
+            SignedPercentage::new_from_m1_1(row[2])?,
+            SignedPercentage::new_from_m1_1(row[3])?,
+        ))
+    }
+}
+
+/// Converts an array of shape (channels, dimensions) into the data of every channel.
+/// Single dimensional types also accept a flat array of shape (channels,).
+/// Every value is validated before anything is returned, so a bad value never results in a partial write.
+pub(crate) fn channel_array_to_wrapped_io_data<T: SensorChannelRow>(array: ArrayViewD<'_, f32>) -> Result<Vec<WrappedIOData>, FeagiDataError> {
+    let array = match array.ndim() {
+        1 if T::DIMENSIONS == 1 => array.insert_axis(Axis(1)),
+        2 => array,
+        _ => return Err(FeagiDataError::BadParameters(format!(
+            "Expected an array of shape (channels, {}), got shape {:?}!", T::DIMENSIONS, array.shape()
+        ))),
+    };
+    if array.shape()[1] != T::DIMENSIONS {
+        return Err(FeagiDataError::BadParameters(format!(
+            "Expected {} values per channel, got {}!", T::DIMENSIONS, array.shape()[1]
+        )));
+    }
+
+    let mut row = [0.0f32; 4];
+    array.outer_iter().enumerate().map(|(channel_index, channel_values)| {
+        for (value, channel_value) in row.iter_mut().zip(channel_values.iter()) {
+            *value = *channel_value;
+        }
+        T::try_from_channel_row(&row[..T::DIMENSIONS])
+            .map(Into::into)
+            .map_err(|e| FeagiDataError::BadParameters(format!("Invalid data for channel {}: {}", channel_index, e)))
+    }).collect()
+}
@@ -0,0 +1,411 @@
+use std::fs::File;
+use std::io::{BufWriter, Read, Write};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use feagi_data_structures::FeagiDataError;
+use feagi_serialization::FeagiByteContainer;
+use crate::feagi_connector_core::PyConnectorAgent;
+use crate::py_error::PyFeagiError;
+
+// File layout (all integers little endian):
+//   header: MAGIC, version (u8), recording start as unix time in microseconds (u64)
+//   then per message: direction (u8), microseconds since the recording start (u64),
+//   increment counter (u16), payload length (u32), payload (the raw FeagiByteContainer bytes)
+
+const MAGIC: &[u8; 8] = b"FEAGITRF";
+const FORMAT_VERSION: u8 = 1;
+const HEADER_LENGTH: usize = 8 + 1 + 8;
+const MESSAGE_HEADER_LENGTH: usize = 1 + 8 + 2 + 4;
+
+/// Longest uninterrupted sleep while replaying, so Ctrl+C is handled promptly
+const MAX_SLEEP_SLICE: Duration = Duration::from_millis(50);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TrafficDirection {
+    Sensory = 0,
+    Motor = 1,
+}
+
+impl TrafficDirection {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TrafficDirection::Sensory => "sensory",
+            TrafficDirection::Motor => "motor",
+        }
+    }
+
+    fn from_name(name: &str) -> Result<Self, FeagiDataError> {
+        match name {
+            "sensory" => Ok(TrafficDirection::Sensory),
+            "motor" => Ok(TrafficDirection::Motor),
+            _ => Err(FeagiDataError::BadParameters(format!("Unknown direction '{}'! Expected 'sensory' or 'motor'", name))),
+        }
+    }
+
+    fn from_byte(byte: u8) -> Result<Self, FeagiDataError> {
+        match byte {
+            0 => Ok(TrafficDirection::Sensory),
+            1 => Ok(TrafficDirection::Motor),
+            _ => Err(FeagiDataError::DeserializationError(format!("Unknown message direction {} in recording!", byte))),
+        }
+    }
+}
+
+fn write_error(action: &str, path: &str, error: std::io::Error) -> PyFeagiError {
+    PyFeagiError::from(FeagiDataError::SerializationError(format!("Unable to {} recording '{}': {}", action, path, error)))
+}
+
+fn read_error(path: &str, error: std::io::Error) -> PyFeagiError {
+    PyFeagiError::from(FeagiDataError::DeserializationError(format!("Unable to read recording '{}': {}", path, error)))
+}
+
+//region Recorder
+
+/// Writes sensory and motor FeagiByteContainer payloads, with their timing and increment counters, to a file.
+/// Attach it to a ConnectorAgent with set_traffic_recorder to capture its traffic, or record payloads manually
+#[pyclass(name = "TrafficRecorder")]
+pub struct PyTrafficRecorder {
+    path: String,
+    writer: Option<BufWriter<File>>,
+    started: Instant,
+    messages_recorded: usize,
+}
+
+impl PyTrafficRecorder {
+    /// Appends the contents of a valid byte container to the recording
+    pub(crate) fn record_container(&mut self, direction: TrafficDirection, byte_container: &FeagiByteContainer) -> PyResult<()> {
+        let increment_counter = byte_container.get_increment_counter().map_err(PyFeagiError::from)?;
+        let payload = byte_container.get_byte_ref();
+        let payload_length = u32::try_from(payload.len()).map_err(|_| PyFeagiError::from(FeagiDataError::SerializationError(format!(
+            "Unable to record a payload of {} bytes, the recording format allows at most {} bytes", payload.len(), u32::MAX
+        ))))?;
+        let timestamp_us = self.started.elapsed().as_micros() as u64;
+        let writer = match self.writer.as_mut() {
+            Some(writer) => writer,
+            None => return Err(PyFeagiError::from(FeagiDataError::BadParameters("Recorder is closed!".into())).into()),
+        };
+
+        let mut message_header = Vec::with_capacity(MESSAGE_HEADER_LENGTH);
+        message_header.push(direction as u8);
+        message_header.extend_from_slice(&timestamp_us.to_le_bytes());
+        message_header.extend_from_slice(&increment_counter.to_le_bytes());
+        message_header.extend_from_slice(&payload_length.to_le_bytes());
+        writer.write_all(&message_header)
+            .and_then(|_| writer.write_all(payload))
+            .map_err(|e| write_error("write to", &self.path, e))?;
+        self.messages_recorded += 1;
+        Ok(())
+    }
+
+    /// Verifies the given bytes are a valid byte container before recording them
+    fn record_bytes(&mut self, direction: TrafficDirection, payload: &[u8]) -> PyResult<()> {
+        let mut byte_container = FeagiByteContainer::new_empty();
+        byte_container.try_write_data_by_copy_and_verify(payload).map_err(PyFeagiError::from)?;
+        self.record_container(direction, &byte_container)
+    }
+}
+
+/// The recorder attached to a connector agent, shared with anything sending or receiving its payloads outside of
+/// Python (such as an agent runtime), so all of its traffic ends up in the same recording
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TrafficRecorderSlot {
+    recorder: Arc<Mutex<Option<Py<PyTrafficRecorder>>>>,
+}
+
+impl TrafficRecorderSlot {
+    pub(crate) fn set(&self, recorder: Option<Py<PyTrafficRecorder>>) -> Result<(), FeagiDataError> {
+        *self.lock()? = recorder;
+        Ok(())
+    }
+
+    pub(crate) fn get(&self, py: Python<'_>) -> Result<Option<Py<PyTrafficRecorder>>, FeagiDataError> {
+        Ok(self.lock()?.as_ref().map(|recorder| recorder.clone_ref(py)))
+    }
+
+    /// Whether a recorder is attached, so callers can skip preparing payloads otherwise. Can be checked without the GIL
+    pub(crate) fn is_attached(&self) -> Result<bool, FeagiDataError> {
+        Ok(self.lock()?.is_some())
+    }
+
+    /// Passes the given byte container to the attached recorder, if any and still open
+    pub(crate) fn record(&self, py: Python<'_>, direction: TrafficDirection, byte_container: &FeagiByteContainer) -> PyResult<()> {
+        let Some(recorder) = self.get(py).map_err(PyFeagiError::from)? else {
+            return Ok(());
+        };
+        let mut recorder = recorder.bind(py).try_borrow_mut()?;
+        if !recorder.is_closed() {
+            recorder.record_container(direction, byte_container)?;
+        }
+        Ok(())
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, Option<Py<PyTrafficRecorder>>>, FeagiDataError> {
+        self.recorder.lock().map_err(|e| FeagiDataError::InternalError(format!("Lock poisoned: {}", e)))
+    }
+}
+
+#[pymethods]
+impl PyTrafficRecorder {
+    /// Creates (or truncates) the recording file at the given path
+    #[new]
+    pub fn new(path: String) -> PyResult<Self> {
+        let file = File::create(&path).map_err(|e| write_error("create", &path, e))?;
+        let mut writer = BufWriter::new(file);
+        let start_unix_us = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_micros() as u64;
+        let mut header = Vec::with_capacity(HEADER_LENGTH);
+        header.extend_from_slice(MAGIC);
+        header.push(FORMAT_VERSION);
+        header.extend_from_slice(&start_unix_us.to_le_bytes());
+        writer.write_all(&header).map_err(|e| write_error("write to", &path, e))?;
+        Ok(PyTrafficRecorder {
+            path,
+            writer: Some(writer),
+            started: Instant::now(),
+            messages_recorded: 0,
+        })
+    }
+
+    /// Records sensory bytes (a serialized FeagiByteContainer), such as those sent with PyAgentClient.send_sensory_bytes
+    pub fn record_sensory(&mut self, payload: &[u8]) -> PyResult<()> {
+        self.record_bytes(TrafficDirection::Sensory, payload)
+    }
+
+    /// Records motor bytes (a serialized FeagiByteContainer), such as those received with PyAgentClient.receive_motor_bytes
+    pub fn record_motor(&mut self, payload: &[u8]) -> PyResult<()> {
+        self.record_bytes(TrafficDirection::Motor, payload)
+    }
+
+    pub fn flush(&mut self) -> PyResult<()> {
+        if let Some(writer) = self.writer.as_mut() {
+            writer.flush().map_err(|e| write_error("write to", &self.path, e))?;
+        }
+        Ok(())
+    }
+
+    /// Flushes and closes the file. Connector agents stop recording to a closed recorder
+    pub fn close(&mut self) -> PyResult<()> {
+        self.flush()?;
+        self.writer = None;
+        Ok(())
+    }
+
+    #[getter]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    #[getter]
+    pub fn messages_recorded(&self) -> usize {
+        self.messages_recorded
+    }
+
+    #[getter]
+    pub fn is_closed(&self) -> bool {
+        self.writer.is_none()
+    }
+
+    fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    fn __exit__(&mut self, _exc_type: Py<PyAny>, _exc_value: Py<PyAny>, _traceback: Py<PyAny>) -> PyResult<bool> {
+        self.close()?;
+        Ok(false)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TrafficRecorder(path='{}', messages_recorded={}, closed={})", self.path, self.messages_recorded, self.writer.is_none())
+    }
+}
+
+//endregion
+
+//region Replayer
+
+/// A single payload of a recording
+#[pyclass(name = "RecordedMessage")]
+#[derive(Clone)]
+pub struct PyRecordedMessage {
+    direction: TrafficDirection,
+    timestamp_us: u64,
+    increment_counter: u16,
+    payload: Vec<u8>,
+}
+
+#[pymethods]
+impl PyRecordedMessage {
+    /// "sensory" or "motor"
+    #[getter]
+    pub fn direction(&self) -> &'static str {
+        self.direction.as_str()
+    }
+
+    /// Seconds since the start of the recording
+    #[getter]
+    pub fn timestamp(&self) -> f64 {
+        self.timestamp_us as f64 / 1_000_000.0
+    }
+
+    #[getter]
+    pub fn increment_counter(&self) -> u16 {
+        self.increment_counter
+    }
+
+    /// The serialized FeagiByteContainer
+    #[getter]
+    pub fn payload<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.payload)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "RecordedMessage(direction={}, timestamp={:.6}, increment_counter={}, bytes={})",
+            self.direction.as_str(), self.timestamp(), self.increment_counter, self.payload.len()
+        )
+    }
+}
+
+/// Reads a recording written by TrafficRecorder, and feeds it back at its original (or scaled) timing
+#[pyclass(name = "TrafficReplayer")]
+pub struct PyTrafficReplayer {
+    start_unix_us: u64,
+    messages: Vec<PyRecordedMessage>,
+}
+
+impl PyTrafficReplayer {
+    fn parse(bytes: &[u8]) -> Result<Self, FeagiDataError> {
+        let truncated = || FeagiDataError::DeserializationError("Recording is truncated!".into());
+        if bytes.len() < HEADER_LENGTH || &bytes[..8] != MAGIC {
+            return Err(FeagiDataError::DeserializationError("Not a FEAGI traffic recording!".into()));
+        }
+        if bytes[8] != FORMAT_VERSION {
+            return Err(FeagiDataError::DeserializationError(format!(
+                "Unsupported recording version {}! Expected {}", bytes[8], FORMAT_VERSION
+            )));
+        }
+        let start_unix_us = u64::from_le_bytes(bytes[9..17].try_into().unwrap());
+
+        let mut messages = Vec::new();
+        let mut offset = HEADER_LENGTH;
+        while offset < bytes.len() {
+            let header = bytes.get(offset..offset + MESSAGE_HEADER_LENGTH).ok_or_else(truncated)?;
+            let direction = TrafficDirection::from_byte(header[0])?;
+            let timestamp_us = u64::from_le_bytes(header[1..9].try_into().unwrap());
+            let increment_counter = u16::from_le_bytes(header[9..11].try_into().unwrap());
+            let payload_length = u32::from_le_bytes(header[11..15].try_into().unwrap()) as usize;
+            offset += MESSAGE_HEADER_LENGTH;
+            let payload = bytes.get(offset..offset + payload_length).ok_or_else(truncated)?;
+            offset += payload_length;
+            messages.push(PyRecordedMessage { direction, timestamp_us, increment_counter, payload: payload.to_vec() });
+        }
+        Ok(PyTrafficReplayer { start_unix_us, messages })
+    }
+
+    /// Calls deliver for every message of the given direction, waiting between messages to match the recorded
+    /// timing divided by speed (no waiting if speed is None). Returns the number of messages delivered
+    fn replay<F>(&self, py: Python<'_>, direction: TrafficDirection, speed: Option<f64>, mut deliver: F) -> PyResult<usize>
+    where
+        F: FnMut(Python<'_>, &PyRecordedMessage) -> PyResult<()>,
+    {
+        if let Some(speed) = speed {
+            if !(speed > 0.0 && speed.is_finite()) {
+                return Err(PyFeagiError::from(FeagiDataError::BadParameters(format!(
+                    "Replay speed must be a positive number, got {}!", speed
+                ))).into());
+            }
+        }
+
+        let replay_start = Instant::now();
+        let mut first_timestamp_us: Option<u64> = None;
+        let mut delivered = 0;
+        for message in self.messages.iter().filter(|message| message.direction == direction) {
+            if let Some(speed) = speed {
+                let first_timestamp_us = *first_timestamp_us.get_or_insert(message.timestamp_us);
+                let offset = Duration::from_micros(message.timestamp_us - first_timestamp_us).div_f64(speed);
+                let due = replay_start + offset;
+                loop {
+                    let remaining = due.saturating_duration_since(Instant::now());
+                    if remaining.is_zero() {
+                        break;
+                    }
+                    py.detach(|| std::thread::sleep(remaining.min(MAX_SLEEP_SLICE)));
+                    py.check_signals()?;
+                }
+            }
+            deliver(py, message)?;
+            delivered += 1;
+        }
+        Ok(delivered)
+    }
+}
+
+#[pymethods]
+impl PyTrafficReplayer {
+    #[new]
+    pub fn new(path: &str) -> PyResult<Self> {
+        let mut bytes = Vec::new();
+        File::open(path)
+            .and_then(|mut file| file.read_to_end(&mut bytes))
+            .map_err(|e| read_error(path, e))?;
+        Ok(Self::parse(&bytes).map_err(PyFeagiError::from)?)
+    }
+
+    /// Recorded messages in recording order, optionally only those of the given direction ("sensory" or "motor")
+    #[pyo3(signature = (direction = None))]
+    pub fn get_messages(&self, direction: Option<&str>) -> PyResult<Vec<PyRecordedMessage>> {
+        let direction = direction.map(TrafficDirection::from_name).transpose().map_err(PyFeagiError::from)?;
+        Ok(self.messages.iter()
+            .filter(|message| direction.is_none_or(|direction| message.direction == direction))
+            .cloned()
+            .collect())
+    }
+
+    /// When the recording started, as unix time in seconds
+    #[getter]
+    pub fn start_time(&self) -> f64 {
+        self.start_unix_us as f64 / 1_000_000.0
+    }
+
+    /// Seconds between the start of the recording and its last message
+    #[getter]
+    pub fn duration(&self) -> f64 {
+        self.messages.last().map_or(0.0, |message| message.timestamp())
+    }
+
+    /// Loads every recorded motor payload into the agent's motor cache, and by default decodes it (running the motor
+    /// callbacks) as FEAGI's output would be handled live. Payloads are spaced as recorded, divided by speed.
+    /// A speed of None replays as fast as possible. Returns the number of payloads replayed
+    #[pyo3(signature = (connector_agent, speed = Some(1.0), decode = true))]
+    pub fn replay_motor_into_connector_agent(&self, py: Python<'_>, connector_agent: &Bound<'_, PyConnectorAgent>, speed: Option<f64>, decode: bool) -> PyResult<usize> {
+        self.replay(py, TrafficDirection::Motor, speed, |py, message| {
+            let mut agent = connector_agent.try_borrow_mut()?;
+            agent.motors_load_in_bytes_and_verify(py, PyBytes::new(py, &message.payload).as_any())?;
+            if decode {
+                agent.motors_decode_cached_byte_data_to_motor()?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Sends every recorded sensory payload through the given client's send_sensory_bytes (such as a PyAgentClient),
+    /// spaced as recorded, divided by speed. A speed of None replays as fast as possible. Returns the number of payloads sent
+    #[pyo3(signature = (agent_client, speed = Some(1.0)))]
+    pub fn replay_sensory_into_agent_client(&self, py: Python<'_>, agent_client: &Bound<'_, PyAny>, speed: Option<f64>) -> PyResult<usize> {
+        self.replay(py, TrafficDirection::Sensory, speed, |py, message| {
+            agent_client.call_method1("send_sensory_bytes", (PyBytes::new(py, &message.payload),))?;
+            Ok(())
+        })
+    }
+
+    fn __len__(&self) -> usize {
+        self.messages.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TrafficReplayer(messages={}, duration={:.3})", self.messages.len(), self.duration())
+    }
+}
+
+//endregion
@@ -90,6 +90,19 @@ impl PyCorticalID {
 
     //endregion
 
+    //region Comparison
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+
+    /// Hash of the cortical ID, allowing it to be used as a dict key or in sets.
+    fn __hash__(&self) -> u64 {
+        self.inner.as_u64()
+    }
+
+    //endregion
+
     //region Constants (as class attributes)
 
     /// The length of the cortical ID in bytes (8 bytes).
@@ -0,0 +1,385 @@
+//! Structured diagnostics built from the messages of the feagi-evolutionary validator.
+//!
+//! The validator only reports flat messages, so every known message shape is matched against a rule
+//! giving it a stable code, the JSON path of the offending element and, where possible, a suggested fix.
+//! Paths follow the hierarchical (2.0) genome layout, with cortical areas keyed by their base64 cortical ID.
+
+/// Parts of a validator message a rule can refer to
+struct MessageParts<'a> {
+    /// Every single quoted value, in order of appearance
+    quoted: Vec<&'a str>,
+    /// Index following "index " or "pattern ", if any
+    index: Option<&'a str>,
+}
+
+impl<'a> MessageParts<'a> {
+    fn parse(message: &'a str) -> Self {
+        let quoted = message.split('\'').skip(1).step_by(2).collect();
+        let index = ["index ", "pattern "].iter()
+            .find_map(|marker| message.split_once(marker))
+            .and_then(|(_, rest)| rest.split(|c: char| !c.is_ascii_digit()).next())
+            .filter(|index| !index.is_empty());
+        MessageParts { quoted, index }
+    }
+
+    fn quoted(&self, position: usize) -> &'a str {
+        self.quoted.get(position).copied().unwrap_or("")
+    }
+}
+
+/// A known validator message shape
+struct DiagnosticRule {
+    /// The message must start with this
+    prefix: &'static str,
+    /// The message must also contain this (empty to match any)
+    contains: &'static str,
+    code: &'static str,
+    path: fn(&MessageParts) -> String,
+    suggested_fix: fn(&MessageParts) -> Option<String>,
+}
+
+const RUN_AUTO_FIX: &str = "run auto_fix_genome";
+
+static DIAGNOSTIC_RULES: &[DiagnosticRule] = &[
+    //region Metadata
+    DiagnosticRule {
+        prefix: "Genome ID is empty", contains: "", code: "empty_genome_id",
+        path: |_| json_path(&["genome_id"]),
+        suggested_fix: |_| Some("Set genome_id to a unique, non-empty identifier".into()),
+    },
+    DiagnosticRule {
+        prefix: "Genome version is empty", contains: "", code: "empty_genome_version",
+        path: |_| json_path(&["version"]),
+        suggested_fix: |_| Some("Set version to \"2.0\"".into()),
+    },
+    DiagnosticRule {
+        prefix: "Genome version '", contains: "", code: "unsupported_genome_version",
+        path: |_| json_path(&["version"]),
+        suggested_fix: |_| Some("Migrate the genome to version \"2.0\"".into()),
+    },
+    //endregion
+    //region Cortical Areas
+    DiagnosticRule {
+        prefix: "Genome has no cortical areas defined", contains: "", code: "no_cortical_areas",
+        path: |_| json_path(&["blueprint"]),
+        suggested_fix: |_| Some("Add cortical areas to the blueprint, starting with the core power and death areas".into()),
+    },
+    DiagnosticRule {
+        prefix: "AUTO-FIX: Cortical area '", contains: "zero dimension", code: "zero_cortical_dimensions",
+        path: |parts| json_path(&["blueprint", parts.quoted(0), "block_boundaries"]),
+        suggested_fix: |_| Some(format!("Set every dimension to at least 1, or {}", RUN_AUTO_FIX)),
+    },
+    DiagnosticRule {
+        prefix: "AUTO-FIX: Cortical area '", contains: "neurons_per_voxel", code: "zero_neurons_per_voxel",
+        path: |parts| json_path(&["blueprint", parts.quoted(0), "per_voxel_neuron_cnt"]),
+        suggested_fix: |_| Some(format!("Set per_voxel_neuron_cnt to at least 1, or {}", RUN_AUTO_FIX)),
+    },
+    DiagnosticRule {
+        prefix: "Cortical area '", contains: "very large dimensions", code: "large_cortical_area",
+        path: |parts| json_path(&["blueprint", parts.quoted(0), "block_boundaries"]),
+        suggested_fix: |_| Some("Reduce the dimensions to below 1,000,000 voxels, unless the size is intended".into()),
+    },
+    DiagnosticRule {
+        prefix: "Cortical area '", contains: "empty name", code: "empty_cortical_name",
+        path: |parts| json_path(&["blueprint", parts.quoted(0), "cortical_name"]),
+        suggested_fix: |_| Some("Give the cortical area a descriptive name".into()),
+    },
+    DiagnosticRule {
+        prefix: "Invalid cortical ID length", contains: "", code: "invalid_cortical_id_length",
+        path: |parts| json_path(&["blueprint", parts.quoted(0)]),
+        suggested_fix: |_| Some("Use an 8 character cortical ID, or its 12 character base64 encoding".into()),
+    },
+    DiagnosticRule {
+        prefix: "Custom cortical ID '", contains: "", code: "invalid_custom_cortical_id",
+        path: |parts| json_path(&["blueprint", parts.quoted(0)]),
+        suggested_fix: |_| Some("Use only alphanumeric characters and underscores in custom cortical IDs".into()),
+    },
+    DiagnosticRule {
+        prefix: "Invalid CORE cortical ID", contains: "", code: "invalid_core_cortical_id",
+        path: |parts| json_path(&["blueprint", parts.quoted(0)]),
+        suggested_fix: |_| Some("Use one of the core cortical IDs listed in the message".into()),
+    },
+    DiagnosticRule {
+        prefix: "INVALID OLD-FORMAT cortical ID", contains: "", code: "old_format_cortical_id",
+        path: |parts| json_path(&["blueprint", parts.quoted(0)]),
+        suggested_fix: |_| Some("Migrate the genome to the current cortical ID format".into()),
+    },
+    DiagnosticRule {
+        prefix: "Unknown cortical ID", contains: "", code: "unknown_cortical_id",
+        path: |parts| json_path(&["blueprint", parts.quoted(0)]),
+        suggested_fix: |_| Some("Use a cortical ID generated from the sensor and motor unit templates".into()),
+    },
+    DiagnosticRule {
+        prefix: "Invalid SegmentedVision index", contains: "", code: "invalid_segmented_vision_index",
+        path: |parts| json_path(&["blueprint", parts.quoted(1)]),
+        suggested_fix: |_| Some("Use a segment index between 0 and 8".into()),
+    },
+    DiagnosticRule {
+        prefix: "Cortical ID '", contains: "invalid characters in suffix", code: "invalid_cortical_id_suffix",
+        path: |parts| json_path(&["blueprint", parts.quoted(0)]),
+        suggested_fix: |_| Some("Pad the cortical ID suffix with alphanumeric characters or underscores".into()),
+    },
+    //endregion
+    //region Morphologies
+    DiagnosticRule {
+        prefix: "Genome has no morphologies defined", contains: "", code: "no_morphologies",
+        path: |_| json_path(&["neuron_morphologies"]),
+        suggested_fix: |_| Some("Add the core morphologies (block_to_block, projector)".into()),
+    },
+    DiagnosticRule {
+        prefix: "Missing recommended core morphology", contains: "", code: "missing_core_morphology",
+        path: |parts| json_path(&["neuron_morphologies", parts.quoted(0)]),
+        suggested_fix: |parts| Some(format!("Add the core morphology '{}'", parts.quoted(0))),
+    },
+    DiagnosticRule {
+        prefix: "Morphology '", contains: "has no vectors defined", code: "empty_morphology_vectors",
+        path: |parts| json_path(&["neuron_morphologies", parts.quoted(0), "parameters", "vectors"]),
+        suggested_fix: |_| Some("Define at least one vector".into()),
+    },
+    DiagnosticRule {
+        prefix: "Morphology '", contains: "zero vector at index", code: "zero_morphology_vector",
+        path: |parts| json_path(&["neuron_morphologies", parts.quoted(0), "parameters", "vectors", parts.index.unwrap_or("")]),
+        suggested_fix: |_| Some("Remove the zero vector, as it has no effect".into()),
+    },
+    DiagnosticRule {
+        prefix: "Morphology '", contains: "has no patterns defined", code: "empty_morphology_patterns",
+        path: |parts| json_path(&["neuron_morphologies", parts.quoted(0), "parameters", "patterns"]),
+        suggested_fix: |_| Some("Define at least one pattern".into()),
+    },
+    DiagnosticRule {
+        prefix: "Morphology '", contains: "invalid structure", code: "invalid_morphology_pattern",
+        path: |parts| json_path(&["neuron_morphologies", parts.quoted(0), "parameters", "patterns", parts.index.unwrap_or("")]),
+        suggested_fix: |_| Some("Write the pattern as [source[3], destination[3]]".into()),
+    },
+    DiagnosticRule {
+        prefix: "Morphology '", contains: "zero dimension in src_seed", code: "zero_morphology_src_seed",
+        path: |parts| json_path(&["neuron_morphologies", parts.quoted(0), "parameters", "src_seed"]),
+        suggested_fix: |_| Some("Set every src_seed dimension to at least 1".into()),
+    },
+    DiagnosticRule {
+        prefix: "Morphology '", contains: "empty src_pattern", code: "empty_morphology_src_pattern",
+        path: |parts| json_path(&["neuron_morphologies", parts.quoted(0), "parameters", "src_pattern"]),
+        suggested_fix: |_| Some("Define the src_pattern of the composite morphology".into()),
+    },
+    DiagnosticRule {
+        prefix: "Morphology '", contains: "empty mapper_morphology", code: "empty_mapper_morphology",
+        path: |parts| json_path(&["neuron_morphologies", parts.quoted(0), "parameters", "mapper_morphology"]),
+        suggested_fix: |_| Some("Reference an existing morphology as mapper_morphology".into()),
+    },
+    //endregion
+    //region Physiology
+    DiagnosticRule {
+        prefix: "Invalid simulation_timestep", contains: "", code: "invalid_simulation_timestep",
+        path: |_| json_path(&["physiology", "simulation_timestep"]),
+        suggested_fix: |_| Some(format!("Set simulation_timestep to a positive number of seconds, or {}", RUN_AUTO_FIX)),
+    },
+    DiagnosticRule {
+        prefix: "Very large simulation_timestep", contains: "", code: "large_simulation_timestep",
+        path: |_| json_path(&["physiology", "simulation_timestep"]),
+        suggested_fix: |_| Some("Use a simulation_timestep between 0.01 and 0.1 seconds".into()),
+    },
+    DiagnosticRule {
+        prefix: "max_age is 0", contains: "", code: "zero_max_age",
+        path: |_| json_path(&["physiology", "max_age"]),
+        suggested_fix: |_| Some(format!("Set max_age to a positive value, or {}", RUN_AUTO_FIX)),
+    },
+    DiagnosticRule {
+        prefix: "plasticity_queue_depth is 0", contains: "", code: "zero_plasticity_queue_depth",
+        path: |_| json_path(&["physiology", "plasticity_queue_depth"]),
+        suggested_fix: |_| Some("Set plasticity_queue_depth to a positive value if plasticity is used".into()),
+    },
+    DiagnosticRule {
+        prefix: "Quantization precision '", contains: "normalized", code: "non_canonical_quantization_precision",
+        path: |_| json_path(&["physiology", "quantization_precision"]),
+        suggested_fix: |parts| Some(format!("Use '{}', or {}", parts.quoted(1), RUN_AUTO_FIX)),
+    },
+    DiagnosticRule {
+        prefix: "Invalid quantization_precision", contains: "", code: "invalid_quantization_precision",
+        path: |_| json_path(&["physiology", "quantization_precision"]),
+        suggested_fix: |_| Some(format!("Use 'fp32', 'fp16' or 'int8', or {}", RUN_AUTO_FIX)),
+    },
+    //endregion
+    //region Cross References
+    DiagnosticRule {
+        prefix: "Cortical area '", contains: "non-existent destination area", code: "missing_destination_area",
+        path: |parts| json_path(&["blueprint", parts.quoted(0), "cortical_mapping_dst", parts.quoted(1)]),
+        suggested_fix: |parts| Some(format!("Add cortical area '{}' or remove the mapping to it", parts.quoted(1))),
+    },
+    DiagnosticRule {
+        prefix: "Cortical area '", contains: "invalid destination area ID", code: "invalid_destination_area_id",
+        path: |parts| json_path(&["blueprint", parts.quoted(0), "cortical_mapping_dst", parts.quoted(1)]),
+        suggested_fix: |_| Some("Use a valid cortical ID as the destination of the mapping".into()),
+    },
+    DiagnosticRule {
+        prefix: "Cortical area '", contains: "undefined morphology", code: "undefined_morphology_reference",
+        path: |parts| json_path(&["blueprint", parts.quoted(0), "cortical_mapping_dst"]),
+        suggested_fix: |parts| Some(format!("Define morphology '{}' or use an existing one in the mapping", parts.quoted(1))),
+    },
+    DiagnosticRule {
+        prefix: "Brain region '", contains: "non-existent cortical area", code: "missing_region_cortical_area",
+        path: |parts| json_path(&["brain_regions", parts.quoted(0), "areas"]),
+        suggested_fix: |parts| Some(format!("Add cortical area '{}' or remove it from the brain region", parts.quoted(1))),
+    },
+    DiagnosticRule {
+        prefix: "Composite morphology '", contains: "undefined mapper morphology", code: "undefined_mapper_morphology",
+        path: |parts| json_path(&["neuron_morphologies", parts.quoted(0), "parameters", "mapper_morphology"]),
+        suggested_fix: |parts| Some(format!("Define morphology '{}' or reference an existing one", parts.quoted(1))),
+    },
+    //endregion
+];
+
+/// Code given to messages not matching any known rule
+pub(crate) const UNCLASSIFIED_CODE: &str = "unclassified";
+
+/// Classifies a validator message, returning its code, JSON path and suggested fix
+pub(crate) fn classify_message(message: &str) -> (&'static str, String, Option<String>) {
+    let parts = MessageParts::parse(message);
+    DIAGNOSTIC_RULES.iter()
+        .find(|rule| message.starts_with(rule.prefix) && message.contains(rule.contains))
+        .map(|rule| (rule.code, (rule.path)(&parts), (rule.suggested_fix)(&parts)))
+        .unwrap_or_else(|| (UNCLASSIFIED_CODE, "$".to_string(), None))
+}
+
+/// Builds a JSON path from keys. Numeric keys become array indexes, and keys that are not plain
+/// identifiers (such as base64 cortical IDs) use bracket notation
+pub(crate) fn json_path(keys: &[&str]) -> String {
+    let mut path = String::from("$");
+    for key in keys {
+        if !key.is_empty() && key.chars().all(|c| c.is_ascii_digit()) {
+            path.push_str(&format!("[{}]", key));
+        } else if !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            path.push('.');
+            path.push_str(key);
+        } else {
+            path.push_str(&format!("['{}']", key.replace('\\', "\\\\").replace('\'', "\\'")));
+        }
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use feagi_evo::{create_genome_with_core_areas, create_minimal_genome, string_to_cortical_id, validate_genome, Morphology, MorphologyParameters, MorphologyType, RuntimeGenome};
+    use serde_json::json;
+
+    /// Codes of every message the validator reports for the genome, failing on unclassified ones
+    fn validator_codes(genome: &RuntimeGenome) -> HashSet<&'static str> {
+        let result = validate_genome(genome);
+        result.errors.iter().chain(result.warnings.iter())
+            .map(|message| {
+                let (code, _, _) = classify_message(message);
+                assert_ne!(code, UNCLASSIFIED_CODE, "unclassified validator message: {}", message);
+                code
+            })
+            .collect()
+    }
+
+    fn morphology(morphology_type: MorphologyType, parameters: MorphologyParameters) -> Morphology {
+        Morphology { morphology_type, parameters, class: "custom".into() }
+    }
+
+    #[test]
+    fn validator_messages_of_broken_genome_are_classified() {
+        let mut genome = create_genome_with_core_areas("".into(), "broken".into());
+        genome.metadata.version = "1.0".into();
+        let power_id = string_to_cortical_id("_power").unwrap();
+        let power = genome.cortical_areas.get_mut(&power_id).unwrap();
+        power.name.clear();
+        power.dimensions.width = 0;
+        power.properties.remove("neurons_per_voxel");
+        power.properties.insert("dstmap".into(), json!({
+            "c__fatig": [["undefined_morphology", [1, 1, 1], 1, false]],
+            "!": [],
+        }));
+        let death_id = string_to_cortical_id("_death").unwrap();
+        let death = genome.cortical_areas.get_mut(&death_id).unwrap();
+        death.dimensions.width = 1000;
+        death.dimensions.height = 1000;
+        death.dimensions.depth = 2;
+
+        genome.morphologies.add_morphology("block_to_block".into(), morphology(MorphologyType::Vectors, MorphologyParameters::Vectors { vectors: vec![] }));
+        genome.morphologies.add_morphology("zero_vector".into(), morphology(MorphologyType::Vectors, MorphologyParameters::Vectors { vectors: vec![[0, 0, 0]] }));
+        genome.morphologies.add_morphology("no_patterns".into(), morphology(MorphologyType::Patterns, MorphologyParameters::Patterns { patterns: vec![] }));
+        genome.morphologies.add_morphology("broken_composite".into(), morphology(MorphologyType::Composite, MorphologyParameters::Composite {
+            src_seed: [0, 1, 1],
+            src_pattern: vec![],
+            mapper_morphology: "".into(),
+        }));
+
+        genome.physiology.simulation_timestep = 0.0;
+        genome.physiology.max_age = 0;
+        genome.physiology.plasticity_queue_depth = 0;
+        genome.physiology.quantization_precision = "FP16".into();
+
+        let codes = validator_codes(&genome);
+        for code in [
+            "empty_genome_id", "unsupported_genome_version", "zero_cortical_dimensions", "zero_neurons_per_voxel",
+            "large_cortical_area", "empty_cortical_name", "missing_core_morphology", "empty_morphology_vectors",
+            "zero_morphology_vector", "empty_morphology_patterns", "zero_morphology_src_seed", "empty_morphology_src_pattern",
+            "empty_mapper_morphology", "invalid_simulation_timestep", "zero_max_age", "zero_plasticity_queue_depth",
+            "non_canonical_quantization_precision", "missing_destination_area", "invalid_destination_area_id",
+            "undefined_morphology_reference", "undefined_mapper_morphology",
+        ] {
+            assert!(codes.contains(code), "validator no longer reports {} (got {:?})", code, codes);
+        }
+    }
+
+    #[test]
+    fn validator_messages_of_empty_genome_are_classified() {
+        let mut genome = create_minimal_genome("empty".into(), "empty".into());
+        genome.metadata.version.clear();
+        genome.physiology.simulation_timestep = 5.0;
+        genome.physiology.quantization_precision = "fp64".into();
+
+        let codes = validator_codes(&genome);
+        for code in [
+            "empty_genome_version", "no_cortical_areas", "no_morphologies", "large_simulation_timestep",
+            "invalid_quantization_precision",
+        ] {
+            assert!(codes.contains(code), "validator no longer reports {} (got {:?})", code, codes);
+        }
+    }
+
+    /// Messages the validator cannot currently produce from a parsed genome (cortical IDs always display
+    /// in their base64 form, and brain regions are built by neuroembryogenesis), copied from
+    /// feagi-evolutionary 0.0.1-beta.2
+    #[test]
+    fn unreachable_validator_messages_are_classified() {
+        let cases = [
+            ("Invalid cortical ID length: 'abc' is 3 characters (must be 8 or 12)",
+                "invalid_cortical_id_length", "$.blueprint.abc"),
+            ("Custom cortical ID 'c-custom' contains non-alphanumeric characters",
+                "invalid_custom_cortical_id", "$.blueprint['c-custom']"),
+            ("Invalid CORE cortical ID: '___wrong' - must be one of: [\"___power\", \"___death\"]",
+                "invalid_core_cortical_id", "$.blueprint.___wrong"),
+            ("INVALID OLD-FORMAT cortical ID: 'iic400__' - not compliant with feagi-data-processing templates. ",
+                "old_format_cortical_id", "$.blueprint.iic400__"),
+            ("Unknown cortical ID: 'ixyz____' (first char: 'i', unit: 'xyz') - may not follow feagi-data-processing template system. ",
+                "unknown_cortical_id", "$.blueprint.ixyz____"),
+            ("Invalid SegmentedVision index: '9' in 'isvi9___' - SegmentedVision has 9 areas (indices 0-8)",
+                "invalid_segmented_vision_index", "$.blueprint.isvi9___"),
+            ("Cortical ID 'isvi0-__' has invalid characters in suffix (should be alphanumeric or underscore)",
+                "invalid_cortical_id_suffix", "$.blueprint['isvi0-__']"),
+            ("Morphology 'bad' pattern 2 has invalid structure (expected [src[3], dst[3]])",
+                "invalid_morphology_pattern", "$.neuron_morphologies.bad.parameters.patterns[2]"),
+            ("Brain region 'root' references non-existent cortical area 'X19vd2Vy'",
+                "missing_region_cortical_area", "$.brain_regions.root.areas"),
+        ];
+        for (message, code, path) in cases {
+            let (actual_code, actual_path, _) = classify_message(message);
+            assert_eq!((actual_code, actual_path.as_str()), (code, path), "for message: {}", message);
+        }
+    }
+
+    #[test]
+    fn paths_point_at_offending_elements() {
+        let (_, path, fix) = classify_message("Cortical area 'X19wb3dlcg==' references non-existent destination area 'c__fatig' in dstmap");
+        assert_eq!(path, "$.blueprint['X19wb3dlcg=='].cortical_mapping_dst.c__fatig");
+        assert!(fix.unwrap().contains("c__fatig"));
+        let (_, path, _) = classify_message("Morphology 'zero_vector' has zero vector at index 3: [0, 0, 0]");
+        assert_eq!(path, "$.neuron_morphologies.zero_vector.parameters.vectors[3]");
+        assert_eq!(classify_message("Something new").0, UNCLASSIFIED_CODE);
+    }
+}
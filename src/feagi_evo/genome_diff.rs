@@ -0,0 +1,487 @@
+use std::collections::HashSet;
+use pyo3::prelude::*;
+use serde_json::{Map, Value};
+use feagi_evo::{convert_flat_to_hierarchical_full, load_genome_from_json, save_genome_to_json, string_to_cortical_id, validate_genome};
+use feagi_data_structures::FeagiDataError;
+use super::diagnostics::json_path;
+use super::validator::PyValidationResult;
+use crate::py_error::PyFeagiError;
+
+/// Top level keys regenerated on every save, which are not meaningful changes
+const VOLATILE_KEYS: &[&str] = &["timestamp", "signatures", "stats"];
+
+/// A single difference between two genomes
+#[pyclass]
+#[derive(Clone)]
+pub struct PyGenomeChange {
+    /// "added", "removed" or "changed"
+    #[pyo3(get)]
+    pub kind: String,
+    /// "cortical_area", "morphology", "physiology", "brain_region" or "metadata"
+    #[pyo3(get)]
+    pub section: String,
+    /// ID of the changed cortical area, morphology or brain region. None for physiology and metadata
+    #[pyo3(get)]
+    pub entity: Option<String>,
+    /// JSON path of the changed element, in the hierarchical genome layout
+    #[pyo3(get)]
+    pub path: String,
+    /// JSON encoded value in the old genome. None if added
+    #[pyo3(get)]
+    pub old_value: Option<String>,
+    /// JSON encoded value in the new genome. None if removed
+    #[pyo3(get)]
+    pub new_value: Option<String>,
+}
+
+#[pymethods]
+impl PyGenomeChange {
+    fn __repr__(&self) -> String {
+        format!("GenomeChange(kind={}, section={}, path={})", self.kind, self.section, self.path)
+    }
+
+    fn __str__(&self) -> String {
+        match (&self.old_value, &self.new_value) {
+            (Some(old_value), Some(new_value)) => format!("{} {}: {} -> {}", self.kind, self.path, old_value, new_value),
+            (None, Some(new_value)) => format!("{} {}: {}", self.kind, self.path, new_value),
+            (Some(old_value), None) => format!("{} {}: {}", self.kind, self.path, old_value),
+            (None, None) => format!("{} {}", self.kind, self.path),
+        }
+    }
+}
+
+/// An element changed differently by both sides of a merge, or a reference left dangling by combining both sides
+#[pyclass]
+#[derive(Clone)]
+pub struct PyGenomeConflict {
+    /// "cortical_area", "morphology", "physiology", "brain_region" or "metadata"
+    #[pyo3(get)]
+    pub section: String,
+    /// ID of the conflicting cortical area, morphology or brain region. None for physiology and metadata
+    #[pyo3(get)]
+    pub entity: Option<String>,
+    /// JSON path of the conflicting element, in the hierarchical genome layout
+    #[pyo3(get)]
+    pub path: String,
+    /// JSON encoded values on each side. None where the element does not exist
+    #[pyo3(get)]
+    pub base_value: Option<String>,
+    #[pyo3(get)]
+    pub ours_value: Option<String>,
+    #[pyo3(get)]
+    pub theirs_value: Option<String>,
+}
+
+#[pymethods]
+impl PyGenomeConflict {
+    fn __repr__(&self) -> String {
+        format!("GenomeConflict(section={}, path={})", self.section, self.path)
+    }
+}
+
+/// Result of a three-way genome merge
+#[pyclass]
+#[derive(Clone)]
+pub struct PyGenomeMergeResult {
+    /// The merged genome as JSON. Conflicting elements keep the value of ours
+    #[pyo3(get)]
+    pub merged_json: String,
+    #[pyo3(get)]
+    pub conflicts: Vec<PyGenomeConflict>,
+    /// Validation of the merged genome, loaded the same way as validate_genome
+    #[pyo3(get)]
+    pub validation: PyValidationResult,
+}
+
+#[pymethods]
+impl PyGenomeMergeResult {
+    #[getter]
+    fn has_conflicts(&self) -> bool {
+        !self.conflicts.is_empty()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("GenomeMergeResult(conflicts={}, valid={})", self.conflicts.len(), self.validation.valid)
+    }
+}
+
+/// Loads a genome the same way as the validator, then exports it to the hierarchical layout, so genomes
+/// of any supported format or version are compared on equal terms
+fn load_canonical_genome(genome_json: &str, label: &str) -> PyResult<Value> {
+    let parse_error = |e: String| PyFeagiError::from(FeagiDataError::DeserializationError(format!(
+        "Failed to parse {} genome: {}", label, e
+    )));
+
+    let genome = load_genome_from_json(genome_json).map_err(|e| parse_error(e.to_string()))?;
+    let flat_json = save_genome_to_json(&genome).map_err(|e| parse_error(e.to_string()))?;
+    let flat_genome: Value = serde_json::from_str(&flat_json).map_err(|e| parse_error(e.to_string()))?;
+    let mut genome = convert_flat_to_hierarchical_full(&flat_genome).map_err(|e| parse_error(e.to_string()))?;
+    if let Some(genome) = genome.as_object_mut() {
+        for key in VOLATILE_KEYS {
+            genome.remove(*key);
+        }
+    }
+    Ok(genome)
+}
+
+/// Section and entity of an element from its keys in the hierarchical layout
+fn section_and_entity(keys: &[String]) -> (String, Option<String>) {
+    let section = match keys.first().map(String::as_str) {
+        Some("blueprint") => "cortical_area",
+        Some("neuron_morphologies") => "morphology",
+        Some("physiology") => "physiology",
+        Some("brain_regions") => "brain_region",
+        _ => "metadata",
+    };
+    let entity = match section {
+        "cortical_area" | "morphology" | "brain_region" => keys.get(1).cloned(),
+        _ => None,
+    };
+    (section.to_string(), entity)
+}
+
+fn keys_path(keys: &[String]) -> String {
+    json_path(&keys.iter().map(String::as_str).collect::<Vec<_>>())
+}
+
+/// Union of the keys of the given objects, sorted
+fn union_of_keys<'a>(objects: &[Option<&'a Map<String, Value>>]) -> Vec<&'a String> {
+    let mut keys: Vec<&String> = objects.iter().flatten().flat_map(|object| object.keys()).collect();
+    keys.sort();
+    keys.dedup();
+    keys
+}
+
+/// Recursively lists the differences between two values. Objects are compared key by key, anything else as a whole
+fn diff_values(keys: &mut Vec<String>, old: Option<&Value>, new: Option<&Value>, changes: &mut Vec<PyGenomeChange>) {
+    if old == new {
+        return;
+    }
+    if let (Some(Value::Object(old_object)), Some(Value::Object(new_object))) = (old, new) {
+        for key in union_of_keys(&[Some(old_object), Some(new_object)]) {
+            keys.push(key.clone());
+            diff_values(keys, old_object.get(key), new_object.get(key), changes);
+            keys.pop();
+        }
+        return;
+    }
+
+    let kind = match (old, new) {
+        (None, _) => "added",
+        (_, None) => "removed",
+        _ => "changed",
+    };
+    let (section, entity) = section_and_entity(keys);
+    changes.push(PyGenomeChange {
+        kind: kind.to_string(),
+        section,
+        entity,
+        path: keys_path(keys),
+        old_value: old.map(Value::to_string),
+        new_value: new.map(Value::to_string),
+    });
+}
+
+/// Recursively merges the changes of ours and theirs over base. Returns None if the element is removed
+fn merge_values(keys: &mut Vec<String>, base: Option<&Value>, ours: Option<&Value>, theirs: Option<&Value>, conflicts: &mut Vec<PyGenomeConflict>) -> Option<Value> {
+    if ours == theirs || theirs == base {
+        return ours.cloned();
+    }
+    if ours == base {
+        return theirs.cloned();
+    }
+
+    // Both sides changed the element. Objects changed on both sides may still merge key by key
+    let empty_object = Map::new();
+    let base_object = match base {
+        Some(Value::Object(base_object)) => Some(base_object),
+        None => Some(&empty_object),
+        Some(_) => None,
+    };
+    if let (Some(base_object), Some(Value::Object(ours_object)), Some(Value::Object(theirs_object))) = (base_object, ours, theirs) {
+        let mut merged = Map::new();
+        for key in union_of_keys(&[Some(base_object), Some(ours_object), Some(theirs_object)]) {
+            keys.push(key.clone());
+            if let Some(value) = merge_values(keys, base_object.get(key), ours_object.get(key), theirs_object.get(key), conflicts) {
+                merged.insert(key.clone(), value);
+            }
+            keys.pop();
+        }
+        return Some(Value::Object(merged));
+    }
+
+    let (section, entity) = section_and_entity(keys);
+    conflicts.push(PyGenomeConflict {
+        section,
+        entity,
+        path: keys_path(keys),
+        base_value: base.map(Value::to_string),
+        ours_value: ours.map(Value::to_string),
+        theirs_value: theirs.map(Value::to_string),
+    });
+    ours.cloned()
+}
+
+/// Base64 form of a cortical ID, so IDs written in any supported format compare equal
+fn canonical_cortical_id(cortical_id: &str) -> String {
+    string_to_cortical_id(cortical_id).map(|cortical_id| cortical_id.as_base_64()).unwrap_or_else(|_| cortical_id.to_string())
+}
+
+/// Keys of every element referencing a cortical area or morphology the genome does not define
+fn dangling_references(genome: &Value) -> Vec<Vec<String>> {
+    let blueprint = genome.get("blueprint").and_then(Value::as_object);
+    let morphologies = genome.get("neuron_morphologies").and_then(Value::as_object);
+    let cortical_ids: HashSet<String> = blueprint.into_iter().flat_map(|blueprint| blueprint.keys()).map(|id| canonical_cortical_id(id)).collect();
+    let morphology_exists = |morphology_id: &str| morphologies.is_some_and(|morphologies| morphologies.contains_key(morphology_id));
+    let mut dangling = Vec::new();
+
+    // Mappings to missing areas, or through missing morphologies. Rules are either objects or [morphology_id, ...] arrays
+    for (area_id, area) in blueprint.into_iter().flatten() {
+        for (destination_id, rules) in area.get("cortical_mapping_dst").and_then(Value::as_object).into_iter().flatten() {
+            let undefined_morphology = rules.as_array().into_iter().flatten()
+                .filter_map(|rule| rule.get("morphology_id").or_else(|| rule.get(0)).and_then(Value::as_str))
+                .any(|morphology_id| !morphology_exists(morphology_id));
+            if undefined_morphology || !cortical_ids.contains(&canonical_cortical_id(destination_id)) {
+                dangling.push(vec!["blueprint".into(), area_id.clone(), "cortical_mapping_dst".into(), destination_id.clone()]);
+            }
+        }
+    }
+
+    for (region_id, region) in genome.get("brain_regions").and_then(Value::as_object).into_iter().flatten() {
+        let missing_area = region.get("areas").and_then(Value::as_array).into_iter().flatten()
+            .filter_map(Value::as_str)
+            .any(|area_id| !cortical_ids.contains(&canonical_cortical_id(area_id)));
+        if missing_area {
+            dangling.push(vec!["brain_regions".into(), region_id.clone(), "areas".into()]);
+        }
+    }
+
+    for (morphology_id, morphology) in morphologies.into_iter().flatten() {
+        let mapper_morphology = morphology.pointer("/parameters/mapper_morphology").and_then(Value::as_str);
+        if mapper_morphology.is_some_and(|mapper_morphology| !mapper_morphology.is_empty() && !morphology_exists(mapper_morphology)) {
+            dangling.push(vec!["neuron_morphologies".into(), morphology_id.clone(), "parameters".into(), "mapper_morphology".into()]);
+        }
+    }
+    dangling
+}
+
+fn value_at<'a>(genome: &'a Value, keys: &[String]) -> Option<&'a Value> {
+    keys.iter().try_fold(genome, |value, key| value.get(key))
+}
+
+/// Compare two genomes
+///
+/// Both genomes are loaded (and migrated) as for validation, so genomes of different formats can be compared.
+/// Regenerated fields (timestamp, signatures, stats) are ignored.
+///
+/// # Arguments
+/// * `old_genome_json` - JSON string of the earlier revision
+/// * `new_genome_json` - JSON string of the later revision
+///
+/// # Returns
+/// * `list[PyGenomeChange]` - Every added, removed or changed element, sorted by path
+///
+/// # Example
+/// ```python
+/// from feagi_rust_py_libs.genome import diff_genomes
+///
+/// for change in diff_genomes(old_json, new_json):
+///     if change.section == "cortical_area" and change.kind == "added":
+///         print(f"New cortical area {change.entity}")
+/// ```
+#[pyfunction]
+#[pyo3(name = "diff_genomes")]
+pub fn py_diff_genomes(old_genome_json: &str, new_genome_json: &str) -> PyResult<Vec<PyGenomeChange>> {
+    let old_genome = load_canonical_genome(old_genome_json, "old")?;
+    let new_genome = load_canonical_genome(new_genome_json, "new")?;
+
+    let mut changes = Vec::new();
+    diff_values(&mut Vec::new(), Some(&old_genome), Some(&new_genome), &mut changes);
+    Ok(changes)
+}
+
+/// Three-way merge of two revisions of a genome derived from a common base
+///
+/// Elements changed by only one side take that change. Elements changed differently by both sides are
+/// reported as conflicts and keep the value of ours. References that only dangle once both sides are combined
+/// (such as a mapping added by ours to a cortical area deleted by theirs) are reported as conflicts too.
+/// Regenerated fields (timestamp, signatures, stats) are taken from ours. The merged genome is then loaded
+/// and validated the same way as validate_genome.
+///
+/// # Arguments
+/// * `base_genome_json` - JSON string of the common ancestor
+/// * `ours_genome_json` - JSON string of our revision
+/// * `theirs_genome_json` - JSON string of their revision
+///
+/// # Returns
+/// * `PyGenomeMergeResult` - The merged genome (hierarchical layout), the conflicts found and its validation
+///
+/// # Raises
+/// * `DeserializationError` - If any input genome, or the merged genome, cannot be loaded
+///
+/// # Example
+/// ```python
+/// from feagi_rust_py_libs.genome import merge_genomes
+///
+/// result = merge_genomes(base_json, ours_json, theirs_json)
+/// for conflict in result.conflicts:
+///     print(f"Conflict at {conflict.path}: {conflict.ours_value} vs {conflict.theirs_value}")
+/// if not result.validation.valid:
+///     print(result.validation)
+/// ```
+#[pyfunction]
+#[pyo3(name = "merge_genomes")]
+pub fn py_merge_genomes(base_genome_json: &str, ours_genome_json: &str, theirs_genome_json: &str) -> PyResult<PyGenomeMergeResult> {
+    let base_genome = load_canonical_genome(base_genome_json, "base")?;
+    let ours_genome = load_canonical_genome(ours_genome_json, "ours")?;
+    let theirs_genome = load_canonical_genome(theirs_genome_json, "theirs")?;
+
+    let mut conflicts = Vec::new();
+    let mut merged = merge_values(&mut Vec::new(), Some(&base_genome), Some(&ours_genome), Some(&theirs_genome), &mut conflicts)
+        .unwrap_or(Value::Object(Map::new()));
+
+    for keys in dangling_references(&merged) {
+        let path = keys_path(&keys);
+        if conflicts.iter().any(|conflict| conflict.path == path) {
+            continue;
+        }
+        let (section, entity) = section_and_entity(&keys);
+        conflicts.push(PyGenomeConflict {
+            section,
+            entity,
+            path,
+            base_value: value_at(&base_genome, &keys).map(Value::to_string),
+            ours_value: value_at(&ours_genome, &keys).map(Value::to_string),
+            theirs_value: value_at(&theirs_genome, &keys).map(Value::to_string),
+        });
+    }
+
+    // Restore the regenerated fields of ours, which were left out of the comparison
+    let ours_original: Value = serde_json::from_str(ours_genome_json)
+        .map_err(|e| PyFeagiError::from(FeagiDataError::DeserializationError(format!("Failed to parse ours genome: {}", e))))?;
+    if let (Some(merged), Some(ours_original)) = (merged.as_object_mut(), ours_original.as_object()) {
+        for key in VOLATILE_KEYS {
+            if let Some(value) = ours_original.get(*key) {
+                merged.insert(key.to_string(), value.clone());
+            }
+        }
+    }
+
+    let merged_json = serde_json::to_string_pretty(&merged)
+        .map_err(|e| PyFeagiError::from(FeagiDataError::SerializationError(format!("Failed to serialize merged genome: {}", e))))?;
+    let merged_genome = load_genome_from_json(&merged_json)
+        .map_err(|e| PyFeagiError::from(FeagiDataError::DeserializationError(format!("Failed to load merged genome: {}", e))))?;
+    let validation = validate_genome(&merged_genome).into();
+    Ok(PyGenomeMergeResult { merged_json, conflicts, validation })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use feagi_evo::ESSENTIAL_GENOME_JSON;
+    use serde_json::json;
+
+    const FORWARD: &str = "Y19fZm9yX18=";
+    const M2_FW: &str = "Y0tRTTJfX18=";
+
+    /// The essential genome in the hierarchical layout, edited by the given function
+    fn essential_genome(edit: impl FnOnce(&mut Value)) -> String {
+        let mut genome = load_canonical_genome(ESSENTIAL_GENOME_JSON, "essential").unwrap();
+        edit(&mut genome);
+        genome.to_string()
+    }
+
+    fn projector_mapping() -> Value {
+        json!([{"morphology_id": "projector", "morphology_scalar": [1, 1, 1], "postSynapticCurrent_multiplier": 1.0, "plasticity_flag": false}])
+    }
+
+    #[test]
+    fn diff_lists_changes_by_path() {
+        let old = essential_genome(|_| {});
+        let new = essential_genome(|genome| {
+            genome["blueprint"].as_object_mut().unwrap().remove(M2_FW);
+            genome["blueprint"][FORWARD]["cortical_name"] = json!("go_forward");
+            genome["physiology"]["max_age"] = json!(12345);
+            genome["timestamp"] = json!(1.0);
+        });
+
+        let changes = py_diff_genomes(&old, &new).unwrap();
+        let summary: Vec<(&str, &str, Option<&str>)> = changes.iter()
+            .map(|change| (change.kind.as_str(), change.section.as_str(), change.entity.as_deref()))
+            .collect();
+        assert_eq!(summary, vec![
+            ("removed", "cortical_area", Some(M2_FW)),
+            ("changed", "cortical_area", Some(FORWARD)),
+            ("changed", "physiology", None),
+        ]);
+        assert_eq!(changes[1].path, format!("$.blueprint['{}'].cortical_name", FORWARD));
+        assert_eq!(changes[1].new_value.as_deref(), Some("\"go_forward\""));
+        assert!(py_diff_genomes(&old, &old).unwrap().is_empty());
+    }
+
+    #[test]
+    fn merge_takes_one_sided_changes_and_keeps_ours_on_conflict() {
+        let base = essential_genome(|_| {});
+        let ours = essential_genome(|genome| {
+            genome["physiology"]["max_age"] = json!(111);
+            genome["physiology"]["simulation_timestep"] = json!(0.05);
+        });
+        let theirs = essential_genome(|genome| {
+            genome["blueprint"][FORWARD]["cortical_name"] = json!("go_forward");
+            genome["physiology"]["simulation_timestep"] = json!(0.2);
+        });
+
+        let result = py_merge_genomes(&base, &ours, &theirs).unwrap();
+        let merged: Value = serde_json::from_str(&result.merged_json).unwrap();
+        assert_eq!(merged["physiology"]["max_age"], json!(111));
+        assert_eq!(merged["blueprint"][FORWARD]["cortical_name"], json!("go_forward"));
+        assert_eq!(merged["physiology"]["simulation_timestep"], json!(0.05));
+        assert_eq!(result.conflicts.len(), 1);
+        assert_eq!(result.conflicts[0].path, "$.physiology.simulation_timestep");
+        assert_eq!(result.conflicts[0].theirs_value.as_deref(), Some("0.2"));
+        assert!(result.validation.valid);
+    }
+
+    #[test]
+    fn merge_reports_references_dangling_after_merge() {
+        let base = essential_genome(|_| {});
+        let ours = essential_genome(|genome| {
+            genome["blueprint"][FORWARD]["cortical_mapping_dst"][M2_FW] = projector_mapping();
+        });
+        let theirs = essential_genome(|genome| {
+            genome["blueprint"].as_object_mut().unwrap().remove(M2_FW);
+        });
+
+        let result = py_merge_genomes(&base, &ours, &theirs).unwrap();
+        assert_eq!(result.conflicts.len(), 1);
+        let conflict = &result.conflicts[0];
+        assert_eq!(conflict.path, format!("$.blueprint['{}'].cortical_mapping_dst['{}']", FORWARD, M2_FW));
+        assert_eq!(conflict.entity.as_deref(), Some(FORWARD));
+        assert_eq!((conflict.base_value.is_none(), conflict.ours_value.is_some(), conflict.theirs_value.is_none()), (true, true, true));
+
+        // Without the deletion, the same mapping merges cleanly
+        let result = py_merge_genomes(&base, &ours, &base).unwrap();
+        assert!(!result.has_conflicts());
+    }
+
+    #[test]
+    fn dangling_references_cover_morphologies_and_regions() {
+        let genome = json!({
+            "blueprint": {
+                "X19fcG93ZXI=": {"cortical_mapping_dst": {"X19fcG93ZXI=": [["missing_morphology", [1, 1, 1], 1, false]]}},
+            },
+            "neuron_morphologies": {
+                "composite": {"parameters": {"src_seed": [1, 1, 1], "src_pattern": [[1, 0]], "mapper_morphology": "missing_mapper"}},
+            },
+            "brain_regions": {
+                "root": {"areas": ["X19fcG93ZXI=", "___death"]},
+            },
+        });
+        let paths: Vec<String> = dangling_references(&genome).iter().map(|keys| keys_path(keys)).collect();
+        assert_eq!(paths, vec![
+            "$.blueprint['X19fcG93ZXI='].cortical_mapping_dst['X19fcG93ZXI=']",
+            "$.brain_regions.root.areas",
+            "$.neuron_morphologies.composite.parameters.mapper_morphology",
+        ]);
+    }
+}
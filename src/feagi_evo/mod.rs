@@ -1,6 +1,3 @@
 pub mod validator;
-
-
-
-
-
+pub mod genome_diff;
+mod diagnostics;
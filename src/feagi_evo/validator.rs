@@ -1,5 +1,59 @@
 use pyo3::prelude::*;
-use feagi_evo::{load_genome_from_json, save_genome_to_json, validate_genome, validator::auto_fix_genome};
+use feagi_evo::{load_genome_from_json, save_genome_to_json, validate_genome, validator::auto_fix_genome, RuntimeGenome, ValidationResult};
+use feagi_data_structures::FeagiDataError;
+use super::diagnostics::{classify_message, json_path};
+use crate::py_error::PyFeagiError;
+
+/// A single issue found while validating a genome
+#[pyclass]
+#[derive(Clone)]
+pub struct PyValidationDiagnostic {
+    /// "error" (blocking) or "warning" (non-blocking)
+    #[pyo3(get)]
+    pub severity: String,
+    /// Stable identifier of the kind of issue, such as "zero_cortical_dimensions"
+    #[pyo3(get)]
+    pub code: String,
+    /// JSON path of the offending element, such as "$.physiology.max_age"
+    #[pyo3(get)]
+    pub path: String,
+    #[pyo3(get)]
+    pub message: String,
+    #[pyo3(get)]
+    pub suggested_fix: Option<String>,
+}
+
+impl PyValidationDiagnostic {
+    fn from_message(severity: &str, message: String) -> Self {
+        let (code, path, suggested_fix) = classify_message(&message);
+        PyValidationDiagnostic {
+            severity: severity.to_string(),
+            code: code.to_string(),
+            path,
+            message,
+            suggested_fix,
+        }
+    }
+}
+
+#[pymethods]
+impl PyValidationDiagnostic {
+    fn __repr__(&self) -> String {
+        format!(
+            "ValidationDiagnostic(severity={}, code={}, path={})",
+            self.severity,
+            self.code,
+            self.path
+        )
+    }
+
+    fn __str__(&self) -> String {
+        match &self.suggested_fix {
+            Some(suggested_fix) => format!("[{}] {}: {} (fix: {})", self.code, self.path, self.message, suggested_fix),
+            None => format!("[{}] {}: {}", self.code, self.path, self.message),
+        }
+    }
+}
 
 /// Validation result returned to Python
 #[pyclass]
@@ -8,13 +62,29 @@ pub struct PyValidationResult {
     #[pyo3(get)]
     pub valid: bool,
     #[pyo3(get)]
-    pub errors: Vec<String>,
+    pub errors: Vec<PyValidationDiagnostic>,
     #[pyo3(get)]
-    pub warnings: Vec<String>,
+    pub warnings: Vec<PyValidationDiagnostic>,
+}
+
+impl From<ValidationResult> for PyValidationResult {
+    fn from(result: ValidationResult) -> Self {
+        PyValidationResult {
+            valid: result.valid,
+            errors: result.errors.into_iter().map(|error| PyValidationDiagnostic::from_message("error", error)).collect(),
+            warnings: result.warnings.into_iter().map(|warning| PyValidationDiagnostic::from_message("warning", warning)).collect(),
+        }
+    }
 }
 
 #[pymethods]
 impl PyValidationResult {
+    /// Errors followed by warnings
+    #[getter]
+    fn diagnostics(&self) -> Vec<PyValidationDiagnostic> {
+        self.errors.iter().chain(self.warnings.iter()).cloned().collect()
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "ValidationResult(valid={}, errors={}, warnings={})",
@@ -23,36 +93,153 @@ impl PyValidationResult {
             self.warnings.len()
         )
     }
-    
+
     fn __str__(&self) -> String {
         let mut output = String::new();
         output.push_str(&format!("Valid: {}\n", self.valid));
-        
+
         if !self.errors.is_empty() {
             output.push_str("\nErrors:\n");
             for error in &self.errors {
-                output.push_str(&format!("  - {}\n", error));
+                output.push_str(&format!("  - {}\n", error.__str__()));
             }
         }
-        
+
         if !self.warnings.is_empty() {
             output.push_str("\nWarnings:\n");
             for warning in &self.warnings {
-                output.push_str(&format!("  - {}\n", warning));
+                output.push_str(&format!("  - {}\n", warning.__str__()));
             }
         }
-        
+
         output
     }
 }
 
+/// A single correction applied by auto_fix_genome
+#[pyclass]
+#[derive(Clone)]
+pub struct PyAppliedFix {
+    /// Code of the validation diagnostic this fix resolves
+    #[pyo3(get)]
+    pub code: String,
+    /// JSON path of the corrected element
+    #[pyo3(get)]
+    pub path: String,
+    /// JSON encoded value before the fix
+    #[pyo3(get)]
+    pub old_value: String,
+    /// JSON encoded value after the fix
+    #[pyo3(get)]
+    pub new_value: String,
+}
+
+#[pymethods]
+impl PyAppliedFix {
+    fn __repr__(&self) -> String {
+        format!("AppliedFix(code={}, path={}, {} -> {})", self.code, self.path, self.old_value, self.new_value)
+    }
+}
+
+/// Report of the corrections applied by auto_fix_genome
+#[pyclass]
+#[derive(Clone)]
+pub struct PyAutoFixReport {
+    #[pyo3(get)]
+    pub fixes: Vec<PyAppliedFix>,
+}
+
+#[pymethods]
+impl PyAutoFixReport {
+    /// Number of fixes applied
+    #[getter]
+    fn fixes_applied(&self) -> usize {
+        self.fixes.len()
+    }
+
+    fn __len__(&self) -> usize {
+        self.fixes.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("AutoFixReport(fixes_applied={})", self.fixes.len())
+    }
+}
+
+/// Values auto_fix_genome may correct, captured to report exactly what changed
+struct AutoFixableValues {
+    simulation_timestep: f64,
+    max_age: u64,
+    quantization_precision: String,
+    /// Per cortical area (as displayed): width, height, depth and neurons_per_voxel
+    cortical_areas: Vec<(String, [serde_json::Value; 4])>,
+}
+
+impl AutoFixableValues {
+    fn capture(genome: &RuntimeGenome) -> Self {
+        let mut cortical_areas: Vec<(String, [serde_json::Value; 4])> = genome.cortical_areas.iter()
+            .map(|(cortical_id, area)| {
+                let neurons_per_voxel = area.properties.get("neurons_per_voxel").cloned().unwrap_or(serde_json::Value::Null);
+                (cortical_id.to_string(), [
+                    serde_json::json!(area.dimensions.width),
+                    serde_json::json!(area.dimensions.height),
+                    serde_json::json!(area.dimensions.depth),
+                    neurons_per_voxel,
+                ])
+            })
+            .collect();
+        cortical_areas.sort_by(|a, b| a.0.cmp(&b.0));
+        AutoFixableValues {
+            simulation_timestep: genome.physiology.simulation_timestep,
+            max_age: genome.physiology.max_age,
+            quantization_precision: genome.physiology.quantization_precision.clone(),
+            cortical_areas,
+        }
+    }
+
+    /// Lists the values that differ between the capture before and after fixing.
+    /// precision_normalized tells whether the original quantization precision was valid but not canonical
+    fn applied_fixes(&self, fixed: &AutoFixableValues, precision_normalized: bool) -> Vec<PyAppliedFix> {
+        let mut fixes = Vec::new();
+        let mut push_if_changed = |code: &str, path: String, old_value: serde_json::Value, new_value: serde_json::Value| {
+            if old_value != new_value {
+                fixes.push(PyAppliedFix {
+                    code: code.to_string(),
+                    path,
+                    old_value: old_value.to_string(),
+                    new_value: new_value.to_string(),
+                });
+            }
+        };
+
+        push_if_changed("invalid_simulation_timestep", json_path(&["physiology", "simulation_timestep"]),
+            serde_json::json!(self.simulation_timestep), serde_json::json!(fixed.simulation_timestep));
+        push_if_changed("zero_max_age", json_path(&["physiology", "max_age"]),
+            serde_json::json!(self.max_age), serde_json::json!(fixed.max_age));
+        let precision_code = if precision_normalized { "non_canonical_quantization_precision" } else { "invalid_quantization_precision" };
+        push_if_changed(precision_code, json_path(&["physiology", "quantization_precision"]),
+            serde_json::json!(self.quantization_precision), serde_json::json!(fixed.quantization_precision));
+
+        // Auto fixing never adds or removes cortical areas, so both captures are in the same order
+        for ((cortical_id, values), (_, fixed_values)) in self.cortical_areas.iter().zip(fixed.cortical_areas.iter()) {
+            for (dimension, (value, fixed_value)) in values[..3].iter().zip(fixed_values[..3].iter()).enumerate() {
+                push_if_changed("zero_cortical_dimensions", json_path(&["blueprint", cortical_id, "block_boundaries", &dimension.to_string()]),
+                    value.clone(), fixed_value.clone());
+            }
+            push_if_changed("zero_neurons_per_voxel", json_path(&["blueprint", cortical_id, "per_voxel_neuron_cnt"]),
+                values[3].clone(), fixed_values[3].clone());
+        }
+        fixes
+    }
+}
+
 /// Validate a genome from JSON string
 ///
 /// # Arguments
 /// * `genome_json` - JSON string containing genome data (must follow FEAGI 2.0 format)
 ///
 /// # Returns
-/// * `PyValidationResult` - Validation result with errors and warnings
+/// * `PyValidationResult` - Validation result with structured errors and warnings
 ///
 /// # Example
 /// ```python
@@ -70,26 +257,21 @@ impl PyValidationResult {
 /// result = validate_genome(json.dumps(genome))
 /// if not result.valid:
 ///     for error in result.errors:
-///         print(f"ERROR: {error}")
+///         print(f"ERROR {error.code} at {error.path}: {error.message}")
 /// ```
 #[pyfunction]
+#[pyo3(name = "validate_genome")]
 pub fn py_validate_genome(genome_json: &str) -> PyResult<PyValidationResult> {
     // Load genome from JSON
     let genome = load_genome_from_json(genome_json).map_err(|e| {
-        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+        PyFeagiError::from(FeagiDataError::DeserializationError(format!(
             "Failed to parse genome (invalid format): {}",
             e
-        ))
+        )))
     })?;
-    
+
     // Validate
-    let result = validate_genome(&genome);
-    
-    Ok(PyValidationResult {
-        valid: result.valid,
-        errors: result.errors,
-        warnings: result.warnings,
-    })
+    Ok(validate_genome(&genome).into())
 }
 
 /// Auto-fix common genome issues (zero dimensions, missing physiology, etc.)
@@ -100,7 +282,7 @@ pub fn py_validate_genome(genome_json: &str) -> PyResult<PyValidationResult> {
 /// * `genome_json` - JSON string containing genome data
 ///
 /// # Returns
-/// * `tuple` - (fixed_json_string, num_fixes_applied)
+/// * `tuple` - (fixed_json_string, PyAutoFixReport listing every fix applied)
 ///
 /// # Example
 /// ```python
@@ -108,41 +290,57 @@ pub fn py_validate_genome(genome_json: &str) -> PyResult<PyValidationResult> {
 /// import json
 ///
 /// genome = { ... }  # Genome with issues
-/// fixed_json, fixes_applied = auto_fix_genome(json.dumps(genome))
+/// fixed_json, report = auto_fix_genome(json.dumps(genome))
 /// genome = json.loads(fixed_json)
-/// print(f"Applied {fixes_applied} automatic fixes")
+/// for fix in report.fixes:
+///     print(f"Fixed {fix.path}: {fix.old_value} -> {fix.new_value}")
 /// ```
 #[pyfunction]
-pub fn py_auto_fix_genome(genome_json: &str) -> PyResult<(String, usize)> {
+#[pyo3(name = "auto_fix_genome")]
+pub fn py_auto_fix_genome(genome_json: &str) -> PyResult<(String, PyAutoFixReport)> {
     // Load genome from JSON
     let mut genome = load_genome_from_json(genome_json).map_err(|e| {
-        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+        PyFeagiError::from(FeagiDataError::DeserializationError(format!(
             "Failed to parse genome: {}",
             e
-        ))
+        )))
     })?;
-    
+
     // Apply auto-fixes
+    let original_values = AutoFixableValues::capture(&genome);
+    let precision_normalized = validate_genome(&genome).warnings.iter()
+        .any(|warning| classify_message(warning).0 == "non_canonical_quantization_precision");
     let fixes_applied = auto_fix_genome(&mut genome);
-    
+    let fixes = original_values.applied_fixes(&AutoFixableValues::capture(&genome), precision_normalized);
+    if fixes.len() != fixes_applied {
+        tracing::warn!("[GENOME] auto_fix_genome applied {} fixes, but {} were detected", fixes_applied, fixes.len());
+    }
+
     // Convert back to JSON
     let fixed_json = save_genome_to_json(&genome).map_err(|e| {
-        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Failed to serialize fixed genome: {}", e))
+        PyFeagiError::from(FeagiDataError::SerializationError(format!("Failed to serialize fixed genome: {}", e)))
     })?;
-    
-    Ok((fixed_json, fixes_applied))
+
+    Ok((fixed_json, PyAutoFixReport { fixes }))
 }
 
-/// Register the genome validation module with Python
+/// Register the genome validation, diff and merge module with Python
 pub fn register_module(py: Python, parent_module: &Bound<'_, PyModule>) -> PyResult<()> {
-    let genome_module = PyModule::new_bound(py, "genome")?;
-    
+    let genome_module = PyModule::new(py, "genome")?;
+
     genome_module.add_function(wrap_pyfunction!(py_validate_genome, &genome_module)?)?;
     genome_module.add_function(wrap_pyfunction!(py_auto_fix_genome, &genome_module)?)?;
     genome_module.add_class::<PyValidationResult>()?;
-    
+    genome_module.add_class::<PyValidationDiagnostic>()?;
+    genome_module.add_class::<PyAutoFixReport>()?;
+    genome_module.add_class::<PyAppliedFix>()?;
+    genome_module.add_function(wrap_pyfunction!(super::genome_diff::py_diff_genomes, &genome_module)?)?;
+    genome_module.add_function(wrap_pyfunction!(super::genome_diff::py_merge_genomes, &genome_module)?)?;
+    genome_module.add_class::<super::genome_diff::PyGenomeChange>()?;
+    genome_module.add_class::<super::genome_diff::PyGenomeConflict>()?;
+    genome_module.add_class::<super::genome_diff::PyGenomeMergeResult>()?;
+
     parent_module.add_submodule(&genome_module)?;
-    
+
     Ok(())
 }
-
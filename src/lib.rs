@@ -3,13 +3,14 @@
 //! be reflected to the 'feagi_data_processing.pyi.template' file!
 
 mod py_error;
+mod py_state_encoding;
 mod useful_macros;
 mod feagi_data_structures;
 pub mod feagi_data_serialization;
 pub use feagi_data_serialization as feagi_serialization;
 mod feagi_connector_core;
 mod feagi_agent_sdk;
-//mod feagi_evo;
+mod feagi_evo;
 
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
@@ -59,6 +60,9 @@ macro_rules! add_python_class {
             }
 
             current_module.add_class::<$class>()?;
+            // Point the class at the module it is exposed in (instead of builtins), so pickle can find it
+            let class_name = <$class as pyo3::type_object::PyTypeInfo>::NAME;
+            current_module.getattr(class_name)?.setattr("__module__", &full_path)?;
         }
     };
 }
@@ -69,6 +73,25 @@ macro_rules! add_python_class {
 #[pymodule]
 fn feagi_rust_py_libs(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     
+    //region Errors
+
+    m.add("FeagiError", py.get_type::<py_error::FeagiError>())?;
+    m.add("DeserializationError", py.get_type::<py_error::DeserializationError>())?;
+    m.add("SerializationError", py.get_type::<py_error::SerializationError>())?;
+    m.add("BadParametersError", py.get_type::<py_error::BadParametersError>())?;
+    m.add("NeuronError", py.get_type::<py_error::NeuronError>())?;
+    m.add("InternalError", py.get_type::<py_error::InternalError>())?;
+    m.add("ConstError", py.get_type::<py_error::ConstError>())?;
+    m.add("NotImplementedError", py.get_type::<py_error::NotImplementedError>())?;
+    m.add("AgentError", py.get_type::<py_error::AgentError>())?;
+    m.add("ConnectionError", py.get_type::<py_error::ConnectionError>())?;
+    m.add("TimeoutError", py.get_type::<py_error::TimeoutError>())?;
+    m.add("HttpError", py.get_type::<py_error::HttpError>())?;
+    m.add("RegistrationError", py.get_type::<py_error::RegistrationError>())?;
+    m.add("ConfigurationError", py.get_type::<py_error::ConfigurationError>())?;
+
+    //endregion
+
     //region Feagi Data Structures
 
     // Genomic
@@ -130,6 +153,7 @@ fn feagi_rust_py_libs(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     
     // Data Types Processing
     add_python_class!(py, m, "connector_core.data_types.processing", feagi_connector_core::data_types::processing::PyImageFrameProcessor);
+    add_python_class!(py, m, "connector_core.data_types.processing", feagi_connector_core::data_types::processing::PyImageFrameSegmentator);
     
     //Wrapped IO Data
     add_python_class!(py, m, "connector_core.wrapped_io_data", feagi_connector_core::wrapped_io_data::PyWrappedIOType);
@@ -142,6 +166,13 @@ fn feagi_rust_py_libs(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     // For now, users can call PipelineStageProperties.new_image_frame_segmentator()
     
     add_python_class!(py, m, "connector_core", feagi_connector_core::PyConnectorAgent);
+    add_python_class!(py, m, "connector_core", feagi_connector_core::PySensorBytesView);
+    add_python_class!(py, m, "connector_core", feagi_connector_core::PySensorRegistration);
+    add_python_class!(py, m, "connector_core", feagi_connector_core::PyMotorRegistration);
+    add_python_class!(py, m, "connector_core", feagi_connector_core::PyChannelRegistration);
+    add_python_class!(py, m, "connector_core", feagi_connector_core::PyTrafficRecorder);
+    add_python_class!(py, m, "connector_core", feagi_connector_core::PyTrafficReplayer);
+    add_python_class!(py, m, "connector_core", feagi_connector_core::PyRecordedMessage);
     
     // Register init_rust_logging function
     m.add_function(pyo3::wrap_pyfunction!(feagi_connector_core::init_rust_logging, m)?)?;
@@ -156,10 +187,10 @@ fn feagi_rust_py_libs(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     
     //endregion
     
-    //region FEAGI Evo (Genome Validation) - Temporarily disabled pending beta.56 migration
+    //region FEAGI Evo (Genome Validation)
     
     // Register the genome validation module
-    // feagi_evo::validator::register_module(py, m)?;
+    feagi_evo::validator::register_module(py, m)?;
     
     //endregion
     
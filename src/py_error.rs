@@ -1,5 +1,8 @@
 use feagi_data_structures::FeagiDataError;
-use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+use pyo3::type_object::PyTypeInfo;
 use pyo3::PyErr;
 use std::fmt;
 
@@ -29,17 +32,125 @@ impl From<FeagiDataError> for PyFeagiError {
 impl From<PyFeagiError> for PyErr {
     fn from(error: PyFeagiError) -> PyErr {
         match error.0 {
-            FeagiDataError::DeserializationError(msg) => PyValueError::new_err(msg),
-            FeagiDataError::SerializationError(msg) => PyValueError::new_err(msg),
-            FeagiDataError::BadParameters(msg) => PyValueError::new_err(msg),
-            FeagiDataError::InternalError(msg) => PyRuntimeError::new_err(msg),
-            FeagiDataError::NeuronError(msg) => PyValueError::new_err(msg),
-            FeagiDataError::NotImplemented => PyRuntimeError::new_err("Function not yet implemented! Please reach out on Github!"),
-            FeagiDataError::ConstError(msg) => PyRuntimeError::new_err(msg),
+            FeagiDataError::DeserializationError(msg) => new_feagi_exception::<DeserializationError>(msg.clone(), "DeserializationError", Some(msg), None),
+            FeagiDataError::SerializationError(msg) => new_feagi_exception::<SerializationError>(msg.clone(), "SerializationError", Some(msg), None),
+            FeagiDataError::BadParameters(msg) => new_feagi_exception::<BadParametersError>(msg.clone(), "BadParameters", Some(msg), None),
+            FeagiDataError::InternalError(msg) => new_feagi_exception::<InternalError>(msg.clone(), "InternalError", Some(msg), None),
+            FeagiDataError::NeuronError(msg) => new_feagi_exception::<NeuronError>(msg.clone(), "NeuronError", Some(msg), None),
+            FeagiDataError::NotImplemented => new_feagi_exception::<NotImplementedError>(
+                "Function not yet implemented! Please reach out on Github!".to_string(), "NotImplemented", None, None
+            ),
+            FeagiDataError::ConstError(msg) => new_feagi_exception::<ConstError>(msg.to_string(), "ConstError", Some(msg.to_string()), None),
         }
     }
 }
 
-impl PyFeagiError {
-    
+/// Custom error type wrapping the errors of the agent SDK, mapped onto the same exception tree
+#[derive(Debug)]
+pub struct PyAgentSdkError(pub feagi_agent::SdkError);
+
+impl fmt::Display for PyAgentSdkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for PyAgentSdkError {}
+
+impl From<feagi_agent::SdkError> for PyAgentSdkError {
+    fn from(error: feagi_agent::SdkError) -> Self {
+        PyAgentSdkError(error)
+    }
+}
+
+impl From<PyAgentSdkError> for PyErr {
+    fn from(error: PyAgentSdkError) -> PyErr {
+        use feagi_agent::SdkError;
+
+        let message = error.0.to_string();
+        let retryable = Some(error.0.is_retryable());
+        match error.0 {
+            SdkError::Zmq(e) => new_feagi_exception::<ConnectionError>(message, "Zmq", Some(e.to_string()), retryable),
+            SdkError::Json(e) => new_feagi_exception::<AgentError>(message, "Json", Some(e.to_string()), retryable),
+            SdkError::RegistrationFailed(msg) => new_feagi_exception::<RegistrationError>(message, "RegistrationFailed", Some(msg), retryable),
+            SdkError::NotRegistered => new_feagi_exception::<RegistrationError>(message, "NotRegistered", None, retryable),
+            SdkError::Timeout(msg) => new_feagi_exception::<TimeoutError>(message, "Timeout", Some(msg), retryable),
+            SdkError::InvalidConfig(msg) => new_feagi_exception::<ConfigurationError>(message, "InvalidConfig", Some(msg), retryable),
+            SdkError::AlreadyConnected => new_feagi_exception::<RegistrationError>(message, "AlreadyConnected", None, retryable),
+            SdkError::HeartbeatFailed(msg) => new_feagi_exception::<ConnectionError>(message, "HeartbeatFailed", Some(msg), retryable),
+            SdkError::ThreadError(msg) => new_feagi_exception::<AgentError>(message, "ThreadError", Some(msg), retryable),
+            SdkError::Other(msg) => new_feagi_exception::<AgentError>(message, "Other", Some(msg), retryable),
+        }
+    }
+}
+
+/// A control request FEAGI answered with an error status, raised as HttpError carrying the status and body
+#[derive(Debug)]
+pub struct PyHttpStatusError {
+    pub method: String,
+    pub route: String,
+    pub status: u16,
+    /// Body of the reply as JSON
+    pub body: String,
+    /// Error message extracted from the body
+    pub message: String,
+}
+
+impl fmt::Display for PyHttpStatusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} failed with status {}: {}", self.method, self.route, self.status, self.message)
+    }
+}
+
+impl std::error::Error for PyHttpStatusError {}
+
+impl From<PyHttpStatusError> for PyErr {
+    fn from(error: PyHttpStatusError) -> PyErr {
+        // Server side failures and rate limiting may go away, client errors will not
+        let retryable = error.status >= 500 || error.status == 429;
+        let exception = new_feagi_exception::<HttpError>(error.to_string(), "HttpStatus", Some(error.message), Some(retryable));
+        Python::attach(|py| {
+            let value = exception.value(py);
+            // The exception class always accepts attributes, so setting them cannot fail
+            let _ = value.setattr("status", error.status);
+            let _ = value.setattr("body", error.body);
+        });
+        exception
+    }
+}
+
+//region Exception Hierarchy
+
+// Declared parents first. Every class is exposed in the root module (see lib.rs)
+create_exception!(feagi_rust_py_libs, FeagiError, PyException, "Base class of every error raised by FEAGI");
+create_exception!(feagi_rust_py_libs, DeserializationError, FeagiError, "Data could not be deserialized");
+create_exception!(feagi_rust_py_libs, SerializationError, FeagiError, "Data could not be serialized");
+create_exception!(feagi_rust_py_libs, BadParametersError, FeagiError, "Invalid parameters were given");
+create_exception!(feagi_rust_py_libs, NeuronError, FeagiError, "Invalid neuron data");
+create_exception!(feagi_rust_py_libs, InternalError, FeagiError, "Internal error, which should be reported");
+create_exception!(feagi_rust_py_libs, ConstError, FeagiError, "Invalid constant definition");
+create_exception!(feagi_rust_py_libs, NotImplementedError, FeagiError, "Function not yet implemented");
+create_exception!(feagi_rust_py_libs, AgentError, FeagiError, "Base class of errors raised by the agent SDK");
+create_exception!(feagi_rust_py_libs, ConnectionError, AgentError, "Communication with FEAGI failed");
+create_exception!(feagi_rust_py_libs, TimeoutError, ConnectionError, "FEAGI did not respond in time");
+create_exception!(feagi_rust_py_libs, HttpError, AgentError, "FEAGI answered a control request with an error status, available as status (and the reply as body)");
+create_exception!(feagi_rust_py_libs, RegistrationError, AgentError, "The agent is not, or could not be, registered with FEAGI");
+create_exception!(feagi_rust_py_libs, ConfigurationError, AgentError, "Invalid agent configuration");
+
+/// Creates an exception of the given class, with the originating error variant (variant), the message
+/// carried by the variant if any (context) and, for agent SDK errors, whether retrying may succeed (retryable)
+fn new_feagi_exception<E: PyTypeInfo>(message: String, variant: &str, context: Option<String>, retryable: Option<bool>) -> PyErr {
+    let exception = PyErr::new::<E, _>(message);
+    Python::attach(|py| {
+        let value = exception.value(py);
+        // The exception classes always accept attributes, so setting them cannot fail
+        let _ = value.setattr("variant", variant);
+        let _ = value.setattr("context", context);
+        if let Some(retryable) = retryable {
+            let _ = value.setattr("retryable", retryable);
+        }
+    });
+    exception
 }
+
+//endregion
@@ -0,0 +1,262 @@
+use feagi_data_structures::FeagiDataError;
+use feagi_data_structures::genomic::cortical_area::{CoreCorticalType, CorticalAreaType, CorticalID, CustomCorticalType, MemoryCorticalType};
+use feagi_data_structures::genomic::cortical_area::io_cortical_area_configuration_flag::{FrameChangeHandling, IOCorticalAreaConfigurationFlag, PercentageNeuronPositioning};
+use feagi_data_structures::neuron_voxels::xyzp::{CorticalMappedXYZPNeuronVoxels, NeuronVoxelXYZP, NeuronVoxelXYZPArrays};
+use feagi_sensorimotor::wrapped_io_data::WrappedIOType;
+use feagi_serialization::FeagiByteStructureType;
+use feagi_sensorimotor::data_types::*;
+use feagi_sensorimotor::data_types::descriptors::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Version of the state encoding, written as the first byte of every state.
+/// Must be bumped whenever the encoding of any type changes
+const STATE_ENCODING_VERSION: u8 = 1;
+
+/// Stable binary encoding of a wrapped rust type, used by the python wrappers for pickling
+pub(crate) trait StateEncoding: Sized {
+    fn encode_state(&self) -> Result<Vec<u8>, FeagiDataError>;
+
+    fn decode_state(state: &[u8]) -> Result<Self, FeagiDataError>;
+
+    /// Overwrites an existing value with the decoded state, as done by __setstate__
+    fn restore_state(&mut self, state: &[u8]) -> Result<(), FeagiDataError> {
+        *self = Self::decode_state(state)?;
+        Ok(())
+    }
+}
+
+fn encode_versioned<T: Serialize>(value: &T) -> Result<Vec<u8>, FeagiDataError> {
+    let mut state = vec![STATE_ENCODING_VERSION];
+    bincode::serialize_into(&mut state, value)
+        .map_err(|e| FeagiDataError::SerializationError(format!("Unable to encode state: {}", e)))?;
+    Ok(state)
+}
+
+fn decode_versioned<T: DeserializeOwned>(state: &[u8]) -> Result<T, FeagiDataError> {
+    match state.split_first() {
+        Some((&STATE_ENCODING_VERSION, payload)) => bincode::deserialize(payload)
+            .map_err(|e| FeagiDataError::DeserializationError(format!("Unable to decode state: {}", e))),
+        Some((version, _)) => Err(FeagiDataError::DeserializationError(format!(
+            "Unsupported state encoding version {}! Expected {}", version, STATE_ENCODING_VERSION
+        ))),
+        None => Err(FeagiDataError::DeserializationError("State is empty!".into())),
+    }
+}
+
+/// Implements StateEncoding for types that can be (de)serialized by serde directly
+macro_rules! impl_serde_state_encoding {
+    ($($rust_name:ty),* $(,)?) => {
+        $(
+            impl StateEncoding for $rust_name {
+                fn encode_state(&self) -> Result<Vec<u8>, FeagiDataError> {
+                    encode_versioned(self)
+                }
+
+                fn decode_state(state: &[u8]) -> Result<Self, FeagiDataError> {
+                    decode_versioned(state)
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_state_encoding!(
+    Percentage,
+    SignedPercentage,
+    Percentage2D,
+    SignedPercentage2D,
+    Percentage3D,
+    SignedPercentage3D,
+    Percentage4D,
+    SignedPercentage4D,
+    ImageFilteringSettings,
+    GazeProperties,
+    ImageXYPoint,
+    ImageXYResolution,
+    ImageXYZDimensions,
+    SegmentedXYImageResolutions,
+    ImageFrameProperties,
+    SegmentedImageFrameProperties,
+    CornerPoints,
+    MiscDataDimensions,
+    CorticalID,
+    WrappedIOType,
+    IOCorticalAreaConfigurationFlag,
+    PercentageNeuronPositioning,
+    FrameChangeHandling,
+    CorticalAreaType,
+    CoreCorticalType,
+    CustomCorticalType,
+    MemoryCorticalType,
+);
+
+impl StateEncoding for FeagiByteStructureType {
+    fn encode_state(&self) -> Result<Vec<u8>, FeagiDataError> {
+        encode_versioned(&(*self as u8))
+    }
+
+    fn decode_state(state: &[u8]) -> Result<Self, FeagiDataError> {
+        let structure_type: u8 = decode_versioned(state)?;
+        FeagiByteStructureType::try_from(structure_type)
+    }
+}
+
+/// Copies raw pixel bytes into a frame, verifying they match its dimensions
+fn fill_image_frame(image_frame: &mut ImageFrame, pixels: &[u8]) -> Result<(), FeagiDataError> {
+    let frame_bytes = image_frame.get_internal_byte_data_mut();
+    if frame_bytes.len() != pixels.len() {
+        return Err(FeagiDataError::DeserializationError(format!(
+            "Expected {} bytes of pixel data, got {}!", frame_bytes.len(), pixels.len()
+        )));
+    }
+    frame_bytes.copy_from_slice(pixels);
+    Ok(())
+}
+
+impl StateEncoding for ImageFrame {
+    fn encode_state(&self) -> Result<Vec<u8>, FeagiDataError> {
+        encode_versioned(&(self.get_image_frame_properties(), self.get_internal_byte_data()))
+    }
+
+    fn decode_state(state: &[u8]) -> Result<Self, FeagiDataError> {
+        let (properties, pixels): (ImageFrameProperties, Vec<u8>) = decode_versioned(state)?;
+        let mut image_frame = ImageFrame::new_from_image_frame_properties(&properties)?;
+        fill_image_frame(&mut image_frame, &pixels)?;
+        Ok(image_frame)
+    }
+
+    /// Restores the pixels in place, as numpy views of the pixel buffer must never see it reallocated
+    fn restore_state(&mut self, state: &[u8]) -> Result<(), FeagiDataError> {
+        let restored = Self::decode_state(state)?;
+        if restored.get_image_frame_properties() != self.get_image_frame_properties() {
+            return Err(FeagiDataError::BadParameters(format!(
+                "Cannot restore a state of {} into an existing image frame of {}!",
+                restored.get_image_frame_properties(), self.get_image_frame_properties()
+            )));
+        }
+        self.get_internal_data_mut().assign(restored.get_internal_data());
+        self.skip_encoding = restored.skip_encoding;
+        Ok(())
+    }
+}
+
+impl StateEncoding for SegmentedImageFrame {
+    fn encode_state(&self) -> Result<Vec<u8>, FeagiDataError> {
+        let segment_pixels: Vec<&[u8]> = self.get_ordered_image_frame_references()
+            .iter()
+            .map(|segment| segment.get_internal_byte_data())
+            .collect();
+        encode_versioned(&(self.get_segmented_image_frame_properties(), segment_pixels))
+    }
+
+    fn decode_state(state: &[u8]) -> Result<Self, FeagiDataError> {
+        let (properties, segment_pixels): (SegmentedImageFrameProperties, Vec<Vec<u8>>) = decode_versioned(state)?;
+        let mut segmented_image_frame = SegmentedImageFrame::from_segmented_image_frame_properties(&properties)?;
+        let segments = segmented_image_frame.get_mut_ordered_image_frame_references();
+        if segments.len() != segment_pixels.len() {
+            return Err(FeagiDataError::DeserializationError(format!(
+                "Expected {} segments, got {}!", segments.len(), segment_pixels.len()
+            )));
+        }
+        for (segment, pixels) in segments.into_iter().zip(segment_pixels.iter()) {
+            fill_image_frame(segment, pixels)?;
+        }
+        Ok(segmented_image_frame)
+    }
+}
+
+impl StateEncoding for MiscData {
+    fn encode_state(&self) -> Result<Vec<u8>, FeagiDataError> {
+        let values: Vec<f32> = self.get_internal_data().iter().copied().collect();
+        encode_versioned(&(self.get_dimensions(), values))
+    }
+
+    fn decode_state(state: &[u8]) -> Result<Self, FeagiDataError> {
+        let (dimensions, values): (MiscDataDimensions, Vec<f32>) = decode_versioned(state)?;
+        let mut misc_data = MiscData::new(&dimensions)?;
+        let internal_data = misc_data.get_internal_data_mut();
+        if internal_data.len() != values.len() {
+            return Err(FeagiDataError::DeserializationError(format!(
+                "Expected {} values, got {}!", internal_data.len(), values.len()
+            )));
+        }
+        for (internal_value, value) in internal_data.iter_mut().zip(values) {
+            *internal_value = value;
+        }
+        Ok(misc_data)
+    }
+}
+
+impl StateEncoding for NeuronVoxelXYZP {
+    fn encode_state(&self) -> Result<Vec<u8>, FeagiDataError> {
+        encode_versioned(&self.as_tuple())
+    }
+
+    fn decode_state(state: &[u8]) -> Result<Self, FeagiDataError> {
+        let (x, y, z, p): (u32, u32, u32, f32) = decode_versioned(state)?;
+        Ok(NeuronVoxelXYZP::new(x, y, z, p))
+    }
+}
+
+impl StateEncoding for NeuronVoxelXYZPArrays {
+    fn encode_state(&self) -> Result<Vec<u8>, FeagiDataError> {
+        encode_versioned(&self.borrow_xyzp_vectors())
+    }
+
+    fn decode_state(state: &[u8]) -> Result<Self, FeagiDataError> {
+        let (x, y, z, p): (Vec<u32>, Vec<u32>, Vec<u32>, Vec<f32>) = decode_versioned(state)?;
+        NeuronVoxelXYZPArrays::new_from_vectors(x, y, z, p)
+    }
+}
+
+/// X, Y, Z and potential vectors of neuron voxels
+type XYZPVectors = (Vec<u32>, Vec<u32>, Vec<u32>, Vec<f32>);
+
+impl StateEncoding for CorticalMappedXYZPNeuronVoxels {
+    fn encode_state(&self) -> Result<Vec<u8>, FeagiDataError> {
+        let mappings: Vec<_> = self.into_iter()
+            .map(|(cortical_id, neurons)| (cortical_id, neurons.borrow_xyzp_vectors()))
+            .collect();
+        encode_versioned(&mappings)
+    }
+
+    fn decode_state(state: &[u8]) -> Result<Self, FeagiDataError> {
+        let mappings: Vec<(CorticalID, XYZPVectors)> = decode_versioned(state)?;
+        let mut neuron_voxels = CorticalMappedXYZPNeuronVoxels::new_with_capacity(mappings.len());
+        for (cortical_id, (x, y, z, p)) in mappings {
+            neuron_voxels.insert(cortical_id, NeuronVoxelXYZPArrays::new_from_vectors(x, y, z, p)?);
+        }
+        Ok(neuron_voxels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn feagi_byte_structure_type_round_trips() {
+        for structure_type in [FeagiByteStructureType::JSON, FeagiByteStructureType::NeuronCategoricalXYZP] {
+            let state = structure_type.encode_state().unwrap();
+            assert_eq!(FeagiByteStructureType::decode_state(&state).unwrap(), structure_type);
+        }
+    }
+
+    #[test]
+    fn cortical_mapped_neuron_voxels_round_trip() {
+        let mut neuron_voxels = CorticalMappedXYZPNeuronVoxels::new();
+        let neurons = NeuronVoxelXYZPArrays::new_from_vectors(vec![1, 4], vec![2, 5], vec![3, 6], vec![0.5, 1.0]).unwrap();
+        neuron_voxels.insert(CoreCorticalType::Power.to_cortical_id(), neurons);
+
+        let state = neuron_voxels.encode_state().unwrap();
+        assert_eq!(CorticalMappedXYZPNeuronVoxels::decode_state(&state).unwrap(), neuron_voxels);
+    }
+
+    #[test]
+    fn decode_rejects_other_versions() {
+        let mut state = FrameChangeHandling::Absolute.encode_state().unwrap();
+        state[0] = STATE_ENCODING_VERSION + 1;
+        assert!(FrameChangeHandling::decode_state(&state).is_err());
+    }
+}
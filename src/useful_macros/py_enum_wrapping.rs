@@ -26,6 +26,8 @@ macro_rules! wrap_flat_enum {
                 bound.get().inner
             }
         }
+
+        $crate::__pickle_and_copy_support!(frozen $py_wrapped_name, $rust_name);
     };
 }
 
@@ -55,5 +57,34 @@ macro_rules! wrap_layered_enum {
         impl $py_wrapped_name {
 
         }
+
+        $crate::__pickle_and_copy_support!($py_wrapped_name, $rust_name);
+    };
+}
+
+/// Pickle and copy support for a pyo3 simple (fieldless) enum, pickled by variant name
+/// Parameter 1: PyEnum name
+/// Parameter 2: List of all variants
+#[macro_export]
+macro_rules! simple_enum_pickle_support {
+    ($py_enum_name:ident, [$($variant:ident),* $(,)?]) => {
+        #[pyo3::pymethods]
+        impl $py_enum_name {
+            fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyAny>, (Bound<'py, pyo3::types::PyType>, &'static str))> {
+                let variant_name = match *slf.borrow() {
+                    $($py_enum_name::$variant => stringify!($variant),)*
+                };
+                let getattr = pyo3::types::PyModule::import(slf.py(), "builtins")?.getattr("getattr")?;
+                Ok((getattr, (pyo3::types::PyAnyMethods::get_type(slf.as_any()), variant_name)))
+            }
+
+            fn __copy__(&self) -> Self {
+                self.clone()
+            }
+
+            fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Self {
+                self.clone()
+            }
+        }
     };
 }
\ No newline at end of file
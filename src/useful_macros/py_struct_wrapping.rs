@@ -28,6 +28,61 @@ macro_rules! create_pyclass {
                 bounded.borrow().inner.clone()
             }
         }
+
+        $crate::__pickle_and_copy_support!($py_wrapped_name, $rust_name);
+    };
+}
+
+// NOTE: technically #[macro_export] is required for visibility
+/// Pickle and copy support for wrappers of clonable rust structs implementing StateEncoding.
+/// The frozen variant skips __setstate__, as frozen pyclasses cannot be mutated
+#[macro_export]
+macro_rules! __pickle_and_copy_support {
+    (frozen $py_wrapped_name:ident, $rust_name:ty) => {
+        #[pyo3::pymethods]
+        impl $py_wrapped_name {
+            /// Stable binary encoding of this object, as used for pickling
+            fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, pyo3::types::PyBytes>> {
+                let state = <$rust_name as $crate::py_state_encoding::StateEncoding>::encode_state(&self.inner)
+                    .map_err($crate::py_error::PyFeagiError::from)?;
+                Ok(pyo3::types::PyBytes::new(py, &state))
+            }
+
+            /// Recreate an object from the output of __getstate__
+            #[staticmethod]
+            fn _from_state(state: &[u8]) -> PyResult<Self> {
+                let inner = <$rust_name as $crate::py_state_encoding::StateEncoding>::decode_state(state)
+                    .map_err($crate::py_error::PyFeagiError::from)?;
+                Ok($py_wrapped_name { inner })
+            }
+
+            fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyAny>, (Bound<'py, pyo3::types::PyBytes>,))> {
+                let from_state = slf.get_type().getattr("_from_state")?;
+                let state = slf.borrow().__getstate__(slf.py())?;
+                Ok((from_state, (state,)))
+            }
+
+            fn __copy__(&self) -> Self {
+                self.clone()
+            }
+
+            fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Self {
+                self.clone()
+            }
+        }
+    };
+
+    ($py_wrapped_name:ident, $rust_name:ty) => {
+        $crate::__pickle_and_copy_support!(frozen $py_wrapped_name, $rust_name);
+
+        #[pyo3::pymethods]
+        impl $py_wrapped_name {
+            fn __setstate__(&mut self, state: &[u8]) -> PyResult<()> {
+                <$rust_name as $crate::py_state_encoding::StateEncoding>::restore_state(&mut self.inner, state)
+                    .map_err($crate::py_error::PyFeagiError::from)?;
+                Ok(())
+            }
+        }
     };
 }
 
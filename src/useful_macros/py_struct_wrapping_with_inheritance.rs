@@ -88,6 +88,44 @@ macro_rules! create_trait_child_pyclass {
             }
         }
 
+        #[pyo3::pymethods]
+        impl $py_class_name_in_rust {
+            /// Stable binary encoding of this object, as used for pickling
+            fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, pyo3::types::PyBytes>> {
+                let state = <$representing_rust_struct as $crate::py_state_encoding::StateEncoding>::encode_state(&self.inner)
+                    .map_err($crate::py_error::PyFeagiError::from)?;
+                Ok(pyo3::types::PyBytes::new(py, &state))
+            }
+
+            fn __setstate__(&mut self, state: &[u8]) -> PyResult<()> {
+                <$representing_rust_struct as $crate::py_state_encoding::StateEncoding>::restore_state(&mut self.inner, state)
+                    .map_err($crate::py_error::PyFeagiError::from)?;
+                Ok(())
+            }
+
+            /// Recreate an object from the output of __getstate__
+            #[staticmethod]
+            fn _from_state(py: Python<'_>, state: &[u8]) -> PyResult<Py<Self>> {
+                let inner = <$representing_rust_struct as $crate::py_state_encoding::StateEncoding>::decode_state(state)
+                    .map_err($crate::py_error::PyFeagiError::from)?;
+                Py::new(py, ($py_class_name_in_rust { inner }, $parent_pyclass_in_rust::new_blank_parent()))
+            }
+
+            fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyAny>, (Bound<'py, pyo3::types::PyBytes>,))> {
+                let from_state = slf.get_type().getattr("_from_state")?;
+                let state = slf.borrow().__getstate__(slf.py())?;
+                Ok((from_state, (state,)))
+            }
+
+            fn __copy__(&self, py: Python<'_>) -> PyResult<Py<Self>> {
+                Py::new(py, (self.clone(), $parent_pyclass_in_rust::new_blank_parent()))
+            }
+
+            fn __deepcopy__(&self, py: Python<'_>, _memo: &Bound<'_, PyAny>) -> PyResult<Py<Self>> {
+                self.__copy__(py)
+            }
+        }
+
     };
 }
 //endregion
@@ -277,6 +315,47 @@ macro_rules! create_trait_child_with_box_pyclass {
             }
         }
 
+        #[pyo3::pymethods]
+        impl $py_class_name_in_rust {
+            /// Stable binary encoding of this object, as used for pickling
+            fn __getstate__<'py>(slf: PyRef<'py, Self>) -> PyResult<Bound<'py, pyo3::types::PyBytes>> {
+                let inner = Self::get_ref(&slf).map_err($crate::py_error::PyFeagiError::from)?;
+                let state = <$rust_child_concrete_type as $crate::py_state_encoding::StateEncoding>::encode_state(inner)
+                    .map_err($crate::py_error::PyFeagiError::from)?;
+                Ok(pyo3::types::PyBytes::new(slf.py(), &state))
+            }
+
+            fn __setstate__(mut slf: PyRefMut<'_, Self>, state: &[u8]) -> PyResult<()> {
+                let inner = Self::get_ref_mut(&mut slf).map_err($crate::py_error::PyFeagiError::from)?;
+                <$rust_child_concrete_type as $crate::py_state_encoding::StateEncoding>::restore_state(inner, state)
+                    .map_err($crate::py_error::PyFeagiError::from)?;
+                Ok(())
+            }
+
+            /// Recreate an object from the output of __getstate__
+            #[staticmethod]
+            fn _from_state(py: Python<'_>, state: &[u8]) -> PyResult<Py<Self>> {
+                let inner = <$rust_child_concrete_type as $crate::py_state_encoding::StateEncoding>::decode_state(state)
+                    .map_err($crate::py_error::PyFeagiError::from)?;
+                Py::new(py, Self::python_new_child_constructor(Box::new(inner)))
+            }
+
+            fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyAny>, (Bound<'py, pyo3::types::PyBytes>,))> {
+                let from_state = slf.get_type().getattr("_from_state")?;
+                let state = Self::__getstate__(slf.borrow())?;
+                Ok((from_state, (state,)))
+            }
+
+            fn __copy__(slf: PyRef<'_, Self>) -> PyResult<Py<Self>> {
+                let boxed_data = Self::get_parent_box(&slf).clone_box();
+                Py::new(slf.py(), Self::python_new_child_constructor(boxed_data))
+            }
+
+            fn __deepcopy__(slf: PyRef<'_, Self>, _memo: &Bound<'_, PyAny>) -> PyResult<Py<Self>> {
+                Self::__copy__(slf)
+            }
+        }
+
 
     };
 }